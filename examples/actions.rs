@@ -0,0 +1,64 @@
+use graphicility::{ActionMap, Binding, Color, Config, GamepadButton, KeyCode, MouseButton};
+
+const BINDINGS_FILE: &str = "bindings.txt";
+
+fn main() {
+    // Use the saved bindings if there are any
+    let actions = ActionMap::load(BINDINGS_FILE).unwrap_or_else(|_| {
+        ActionMap::new()
+            .with("left", [Binding::Key(KeyCode::ArrowLeft), Binding::Key(KeyCode::KeyA), Binding::Gamepad(GamepadButton::DPadLeft)])
+            .with("right", [Binding::Key(KeyCode::ArrowRight), Binding::Key(KeyCode::KeyD), Binding::Gamepad(GamepadButton::DPadRight)])
+            .with("jump", [Binding::Key(KeyCode::Space), Binding::Mouse(MouseButton::Left), Binding::Gamepad(GamepadButton::South)])
+    });
+    let conf = Config::builder()
+        .with_title("Actions")
+        .with_target_fps(60)
+        .with_actions(actions)
+        .build();
+
+    let (mut x, mut y, mut speed_y) = (320.0f32, 300.0f32, 0.0f32);
+    let mut rebinding = false;
+    let mut message = String::new();
+
+    graphicility::run_with(conf, move |ctx| {
+        let input = ctx.input_mut();
+
+        // Press F1 and then anything to bind it to jump
+        if rebinding {
+            if let Some(binding) = input.binding_pressed_any() {
+                input.actions_mut().rebind("jump", [binding]);
+                message = format!("Jump is now {binding}");
+                rebinding = false;
+            }
+        } else if input.key_pressed(KeyCode::F1) {
+            rebinding = true;
+            message = "Press anything to jump with".to_string();
+        } else {
+            if input.action_down("left") { x -= 3.0; }
+            if input.action_down("right") { x += 3.0; }
+            if input.action_pressed("jump") && y >= 300.0 { speed_y = -8.0; }
+        }
+
+        if input.key_pressed(KeyCode::F5) {
+            message = match input.actions().save(BINDINGS_FILE) {
+                Ok(()) => format!("Saved to {BINDINGS_FILE}"),
+                Err(err) => format!("Saving failed: {err}"),
+            };
+        }
+
+        speed_y += 0.4;
+        y = (y + speed_y).min(300.0);
+
+        let (g, input) = ctx.split();
+        g.clear(Color::rgb(20, 20, 30));
+        g.rect((0, 310), (640, 90), Color::rgb(60, 50, 40));
+        g.rect((x as i32 - 10, y as i32 - 10), (20, 20), Color::CYAN);
+
+        g.text((10, 10), "Move with left & right | F1 rebinds jump | F5 saves the bindings", Color::WHITE);
+        for (n, action) in input.actions().actions().enumerate() {
+            let bindings: Vec<String> = input.actions().bindings(action).iter().map(|b| b.to_string()).collect();
+            g.text((10, 30 + n as i32 * 12), format!("{action}: {}", bindings.join(", ")), Color::rgb(160, 160, 160));
+        }
+        g.text((10, 80), message.as_str(), Color::YELLOW);
+    });
+}
@@ -1,30 +1,51 @@
 use graphicility::{Color, MouseButton, KeyCode};
 
 fn main() {
-    let mut points: Vec<(i32, i32)> = vec![];
+    let mut points: Vec<(i32, i32, i32)> = vec![];
+    let mut fills: Vec<(i32, i32)> = vec![];
+    let mut brush_size = 1.0f32;
 
     graphicility::run( move |ctx| {
         let (g, input) = ctx.split();
         // Clear the screen
         g.clear(Color::rgb(20, 20, 20));
 
+        // Scroll to change the brush size, trackpads report pixels instead of lines
+        let scroll = input.scroll_delta();
+        brush_size = (brush_size + scroll.lines.1 + scroll.pixels.1 / 20.0).clamp(1.0, 16.0);
+        let size = brush_size as i32;
+
         // Add points while clicking
-        if input.mouse_down(MouseButton::Left) {
-            if let Some((mx, my)) = input.mouse_pos() {
-                points.push((mx as i32, my as i32));
-            }
+        if input.mouse_down(MouseButton::Left)
+            && let Some((mx, my)) = input.mouse_pos()
+        {
+            points.push((mx as i32, my as i32, size));
+        }
+
+        // Right click works like a paint bucket
+        if input.mouse_pressed(MouseButton::Right)
+            && let Some((mx, my)) = input.mouse_pos()
+        {
+            fills.push((mx as i32, my as i32));
         }
 
         // Clear canvas with Space
         if input.key_down(KeyCode::Space) {
             points.clear();
+            fills.clear();
         }
 
         // Draw the trail
-        for p in &points {
-            g.pixel(*p, Color::YELLOW);
+        for &(x, y, size) in &points {
+            g.rect((x - size / 2, y - size / 2), (size, size), Color::YELLOW);
+        }
+
+        // Fills run on what has been drawn so far, so they come after the trail
+        for f in &fills {
+            g.flood_fill(*f, Color::rgb(40, 90, 160));
         }
 
-        g.text((10, 10), "Left Click to Draw | Space to Clear", Color::WHITE);
+        g.text((10, 10), "Left Click to Draw | Right Click to Fill | Space to Clear", Color::WHITE);
+        g.text((10, 22), format!("Scroll to resize the brush: {size}px"), Color::WHITE);
     });
-}
\ No newline at end of file
+}
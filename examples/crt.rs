@@ -0,0 +1,32 @@
+use graphicility::{Color, Config, PostEffect};
+
+fn main() {
+    let conf = Config::builder()
+        .with_title("CRT")
+        .set_logical_size((320, 200))
+        // Effects run in the order they are added
+        .with_post_effect(PostEffect::ChromaticOffset { offset: 1 })
+        .with_post_effect(PostEffect::Scanlines { intensity: 0.35 })
+        .with_post_effect(PostEffect::Vignette { strength: 0.7 })
+        // We can also write our own filter, this one adds a green tint
+        .with_post_effect(PostEffect::custom(|frame, _w, _h| {
+            for px in frame.chunks_exact_mut(4) {
+                px[1] = px[1].saturating_add(10);
+            }
+        }))
+        .build();
+
+    let mut x = 0.0;
+
+    graphicility::run_with(conf, move |ctx| {
+        let dt = ctx.delta_time();
+        let g = ctx.graphics();
+        let (width, _) = g.logical_size();
+
+        x = (x + 60.0 * dt) % width as f64;
+
+        g.clear(Color::rgb(10, 10, 40));
+        g.rect((x as i32, 80), (40, 40), Color::YELLOW);
+        g.text((10, 10), "READY.", Color::WHITE);
+    });
+}
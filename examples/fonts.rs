@@ -0,0 +1,29 @@
+use graphicility::{Color, Config, Font, TextMode};
+
+fn main() {
+    // Pass the path of any .ttf/.otf file, falls back to DejaVu Sans which most Linux systems have
+    let path = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| "/usr/share/fonts/truetype/dejavu/DejaVuSans.ttf".to_string());
+    let font = Font::from_file(&path).expect("Couldn't load the font");
+    let crisp = font.clone().with_mode(TextMode::Crisp);
+
+    let conf = Config::builder().with_title("Fonts").build();
+
+    graphicility::run_with(conf, move |ctx| {
+        let g = ctx.graphics();
+        g.clear(Color::rgb(30, 30, 45));
+
+        g.text((10, 10), "Built in 8x8 font", Color::WHITE);
+
+        // Different sizes share the same glyph cache
+        let mut y = 30;
+        for size in [12.0, 16.0, 24.0, 32.0] {
+            g.text_with(&font, size, (10, y), "Anti aliased AVATAR Type", Color::YELLOW);
+            g.text_with(&crisp, size, (330, y), "Crisp AVATAR Type", Color::CYAN);
+            y += font.line_height(size) as i32 + 6;
+        }
+
+        g.text_with(&font, 48.0, (10, 250), "Graphicility", Color::rgba(255, 80, 80, 0.8));
+    });
+}
@@ -0,0 +1,45 @@
+use graphicility::{Color, Config, GamepadAxis, GamepadButton};
+
+fn main() {
+    let conf = Config::builder()
+        .with_title("Gamepad")
+        .with_target_fps(60)
+        .build();
+
+    let mut pos = (320.0f32, 200.0f32);
+    let mut status = String::new();
+
+    graphicility::run_with(conf, move |ctx| {
+        let (g, input) = ctx.split();
+        g.clear(Color::rgb(20, 20, 30));
+
+        for id in input.gamepads_connected() {
+            status = format!("Connected: {}", input.gamepad_name(*id).unwrap_or("unknown"));
+        }
+        for id in input.gamepads_disconnected() {
+            status = format!("Disconnected gamepad {id}");
+        }
+        g.text((10, 22), status.as_str(), Color::rgb(160, 160, 160));
+
+        let Some(&pad) = input.gamepads().first() else {
+            g.text((10, 10), "Plug in a gamepad", Color::WHITE);
+            return;
+        };
+
+        // Left stick moves the square, the small drift around the center is already cut off
+        pos.0 += input.gamepad_axis(pad, GamepadAxis::LeftStickX) * 4.0;
+        pos.1 -= input.gamepad_axis(pad, GamepadAxis::LeftStickY) * 4.0;
+
+        // Triggers grow the square, South turns it yellow
+        let size = 20 + (input.gamepad_axis(pad, GamepadAxis::RightTrigger) * 40.0) as i32;
+        let color = if input.gamepad_down(pad, GamepadButton::South) { Color::YELLOW } else { Color::CYAN };
+        g.rect((pos.0 as i32 - size / 2, pos.1 as i32 - size / 2), (size, size), color);
+
+        if input.gamepad_pressed(pad, GamepadButton::Start) {
+            pos = (320.0, 200.0);
+        }
+
+        let name = input.gamepad_name(pad).unwrap_or("unknown");
+        g.text((10, 10), format!("{name} | Left stick moves | South colors | RT grows | Start resets"), Color::WHITE);
+    });
+}
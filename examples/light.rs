@@ -1,4 +1,4 @@
-    use graphicility::{Color, Config};
+    use graphicility::{Color, Config, TextStyle};
 
     fn main() {
         let conf = Config::default();
@@ -16,9 +16,13 @@
                 g.circle(mouse_pos, 20, Color::rgba(0, 255, 255, 0.6));
                 g.circle(mouse_pos, 5, Color::CYAN);
                 
-                g.text((mx as i32 + 10, my as i32 + 10), format!("X: {} Y: {}", mx, my), Color::WHITE);
+                g.with_text_style(TextStyle::default().with_background(Color::BLACK), |g| {
+                    g.text((mx as i32 + 10, my as i32 + 10), format!("X: {} Y: {}", mx, my), Color::WHITE);
+                });
             }
 
-            g.text((10, 10), "Move mouse to shine the light", Color::WHITE);
+            g.with_text_style(TextStyle::default().with_shadow((1, 1), Color::BLACK), |g| {
+                g.text((10, 10), "Move mouse to shine the light", Color::WHITE);
+            });
         });
     }
\ No newline at end of file
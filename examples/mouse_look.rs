@@ -0,0 +1,50 @@
+use graphicility::{Color, Config, CursorGrab, KeyCode, MouseButton};
+
+fn main() {
+    let conf = Config::builder()
+        .with_title("Mouse Look")
+        .with_target_fps(60)
+        .build();
+
+    let mut camera = (0.0f32, 0.0f32);
+
+    graphicility::run_with(conf, move |ctx| {
+        let input = ctx.input_mut();
+
+        // Tab locks and hides the cursor, Escape gives it back
+        if input.key_pressed(KeyCode::Tab) {
+            input.set_cursor_grab(CursorGrab::Locked);
+            input.set_cursor_visible(false);
+        }
+        if input.key_pressed(KeyCode::Escape) {
+            input.set_cursor_grab(CursorGrab::None);
+            input.set_cursor_visible(true);
+        }
+
+        // The deltas keep coming while the cursor is locked or stuck at the screen edge
+        let looking = input.cursor_grab() == CursorGrab::Locked;
+        if looking || input.mouse_down(MouseButton::Right) {
+            let (dx, dy) = input.mouse_delta();
+            camera.0 -= dx / 2.0;
+            camera.1 -= dy / 2.0;
+        }
+
+        let (g, _) = ctx.split();
+        g.clear(Color::rgb(20, 20, 30));
+
+        // A grid that scrolls with the camera
+        let (ox, oy) = (camera.0.rem_euclid(40.0) as i32, camera.1.rem_euclid(40.0) as i32);
+        for x in (ox..640).step_by(40) {
+            g.line((x, 0), (x, 400), Color::rgb(50, 50, 70));
+        }
+        for y in (oy..400).step_by(40) {
+            g.line((0, y), (640, y), Color::rgb(50, 50, 70));
+        }
+        g.rect((318, 190), (4, 20), Color::WHITE);
+        g.rect((310, 198), (20, 4), Color::WHITE);
+
+        let mode = if looking { "Looking around, Escape to stop" } else { "Drag with Right Click | Tab to look around" };
+        g.text((10, 10), mode, Color::WHITE);
+        g.text((10, 22), format!("Camera: {:.0}, {:.0}", camera.0, camera.1), Color::WHITE);
+    });
+}
@@ -0,0 +1,47 @@
+use graphicility::{Color, Config, KeyCode, MouseButton};
+
+// cargo run --example playback --features record -- record demo.rec
+// cargo run --example playback --features record -- play demo.rec
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let builder = Config::builder().with_title("Playback").with_target_fps(60);
+    let conf = match (args.first().map(String::as_str), args.get(1)) {
+        (Some("record"), Some(path)) => builder.record_input_to(path),
+        (Some("play"), Some(path)) => builder.play_input_from(path).set_playback_looping(true),
+        _ => builder,
+    }
+    .build();
+
+    let mut pos = (320.0f32, 200.0f32);
+    let mut trail: Vec<(i32, i32)> = vec![];
+
+    graphicility::run_with(conf, move |ctx| {
+        let dt = ctx.delta_time() as f32;
+        let (g, input) = ctx.split();
+        g.clear(Color::rgb(20, 20, 30));
+
+        // Movement uses the delta time, which is recorded too
+        let speed = 150.0 * dt;
+        if input.key_down(KeyCode::ArrowLeft) { pos.0 -= speed; }
+        if input.key_down(KeyCode::ArrowRight) { pos.0 += speed; }
+        if input.key_down(KeyCode::ArrowUp) { pos.1 -= speed; }
+        if input.key_down(KeyCode::ArrowDown) { pos.1 += speed; }
+
+        if input.mouse_down(MouseButton::Left)
+            && let Some((mx, my)) = input.mouse_pos()
+        {
+            trail.push((mx as i32, my as i32));
+        }
+        if input.key_pressed(KeyCode::Space) {
+            trail.clear();
+        }
+
+        for &(x, y) in &trail {
+            g.rect((x - 1, y - 1), (3, 3), Color::YELLOW);
+        }
+        g.rect((pos.0 as i32 - 8, pos.1 as i32 - 8), (16, 16), Color::CYAN);
+
+        let mode = if input.is_replaying() { "Playing back" } else { "Live" };
+        g.text((10, 10), format!("{mode} | Arrows move | Left Click draws | Space clears"), Color::WHITE);
+    });
+}
@@ -1,4 +1,4 @@
-use graphicility::{Color, Config,};
+use graphicility::{Color, Config, HAlign, Rect, TextAlign, TextStyle, VAlign};
 
 fn main() {
     let conf = Config::builder().with_title("Geometry").build();
@@ -39,7 +39,26 @@ fn main() {
         );
 
         // 6. Text Labels
-        g.text((220, 10), "Graphicility Shapes Demo", Color::WHITE);
+        g.with_text_style(TextStyle::scaled(2), |g| {
+            g.text_aligned((320, 10), "Graphicility Shapes Demo", TextAlign::new(HAlign::Center, VAlign::Top), Color::WHITE);
+        });
+
+        // A button with a centered label
+        let button = Rect::new((260, 350).into(), (120, 30).into());
+        g.rect(button.pos, button.size, Color::rgb(60, 60, 90));
+        g.text_in_rect(button, "Click me", TextAlign::CENTER, Color::WHITE);
+
+        // Word wrapped text box
+        let note = Rect::new((20, 180).into(), (120, 60).into());
+        g.text_box(note, "Long labels wrap to the width of their box", TextAlign::TOP_LEFT, Color::YELLOW);
+
+        // Multi-line text with tab stops in a single call
+        g.with_text_style(TextStyle::default().with_line_spacing(2).with_tab_width(6), |g| {
+            g.text((20, 400), "Shape\tColor\nCircle\tRed\nRect\tBlue", Color::WHITE);
+        });
+
+        // Several colors in one call
+        g.text_markup((20, 440), "HP: {red}12{/} / {green}30{/}", Color::WHITE);
     });
 }
 
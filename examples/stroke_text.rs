@@ -0,0 +1,30 @@
+use graphicility::{Color, Config, StrokeStyle};
+
+fn main() {
+    let conf = Config::builder()
+        .with_title("Stroke Text")
+        .with_target_fps(60)
+        .build();
+
+    let mut time = 0.0;
+
+    graphicility::run_with(conf, move |ctx| {
+        time += ctx.delta_time() as f32;
+        let g = ctx.graphics();
+        g.clear(Color::BLACK);
+
+        // The same font at any size without blocky scaling
+        for (i, size) in [8.0, 12.0, 20.0, 32.0].into_iter().enumerate() {
+            g.stroke_text((20, 20 + i as i32 * 40), "Vector text", StrokeStyle::sized(size), Color::WHITE);
+        }
+
+        // Rotated around its start and drawn with thick strokes
+        let style = StrokeStyle::sized(24.0).with_angle(time).with_thickness(3);
+        g.stroke_text((440, 200), "Spin!", style, Color::CYAN);
+
+        // Measuring works like it does for bitmap text
+        let label = StrokeStyle::sized(14.0).with_thickness(2);
+        let size = g.measure_stroke_text("centered", label);
+        g.stroke_text((320 - size.x / 2, 360), "centered", label, Color::YELLOW);
+    });
+}
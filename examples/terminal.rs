@@ -0,0 +1,53 @@
+use graphicility::{CharGrid, Color, Config, KeyCode};
+
+fn main() {
+    let conf = Config::builder()
+        .with_title("Terminal")
+        .with_target_fps(60)
+        .build();
+
+    // 640x400 logical pixels fit 80x50 cells of the 8x8 font, the last row is a status bar
+    let mut term = CharGrid::new(80, 49);
+    let mut status = CharGrid::new(80, 1);
+    status.set_colors(Color::BLACK, Color::CYAN);
+    term.show_cursor(true);
+    term.set_colors(Color::GREEN, Color::BLACK);
+    term.print("GRAPHICILITY TERMINAL\n\n");
+    term.set_colors(Color::WHITE, Color::BLACK);
+    term.print("Type away, F1 logs a line and F2 clears\n> ");
+
+    let mut lines = 0;
+
+    graphicility::run_with(conf, move |ctx| {
+        let (g, input) = ctx.split();
+
+        // Typed characters, Enter and Backspace have to be handled as keys
+        term.print(input.text_entered());
+        if input.key_pressed(KeyCode::Enter) {
+            term.print("\n> ");
+        }
+        let (col, row) = term.cursor();
+        if input.key_pressed(KeyCode::Backspace) && col > 2 {
+            term.print_at(col - 1, row, " ");
+            term.set_cursor(col - 1, row);
+        }
+
+        if input.key_pressed(KeyCode::F1) {
+            lines += 1;
+            term.set_colors(Color::YELLOW, Color::BLACK);
+            term.print(&format!("\n[{lines:04}]"));
+            term.set_colors(Color::WHITE, Color::BLACK);
+            term.print("\tSomething happened\n> ");
+        }
+        if input.key_pressed(KeyCode::F2) {
+            term.clear();
+            term.print("> ");
+        }
+
+        status.clear();
+        status.print_at(1, 0, &format!("{lines} lines logged"));
+
+        g.char_grid((0, 0), &term);
+        g.char_grid((0, 392), &status);
+    });
+}
@@ -0,0 +1,355 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::io;
+use std::path::Path;
+use std::str::FromStr;
+
+use winit::event::MouseButton;
+use winit::keyboard::KeyCode;
+
+use crate::gamepad::GamepadButton;
+
+/// A key, mouse button or gamepad button an action is bound to. <br>
+/// Gamepad bindings listen to every connected gamepad.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Binding {
+    Key(KeyCode),
+    Mouse(MouseButton),
+    Gamepad(GamepadButton),
+}
+
+/// Every key code, winit has no way to get one back from its name
+const KEY_CODES: &[KeyCode] = {
+    use KeyCode::*;
+    &[
+        Backquote, Backslash, BracketLeft, BracketRight, Comma, Digit0, Digit1, Digit2,
+        Digit3, Digit4, Digit5, Digit6, Digit7, Digit8, Digit9, Equal,
+        IntlBackslash, IntlRo, IntlYen, KeyA, KeyB, KeyC, KeyD, KeyE,
+        KeyF, KeyG, KeyH, KeyI, KeyJ, KeyK, KeyL, KeyM,
+        KeyN, KeyO, KeyP, KeyQ, KeyR, KeyS, KeyT, KeyU,
+        KeyV, KeyW, KeyX, KeyY, KeyZ, Minus, Period, Quote,
+        Semicolon, Slash, AltLeft, AltRight, Backspace, CapsLock, ContextMenu, ControlLeft,
+        ControlRight, Enter, SuperLeft, SuperRight, ShiftLeft, ShiftRight, Space, Tab,
+        Convert, KanaMode, Lang1, Lang2, Lang3, Lang4, Lang5, NonConvert,
+        Delete, End, Help, Home, Insert, PageDown, PageUp, ArrowDown,
+        ArrowLeft, ArrowRight, ArrowUp, NumLock, Numpad0, Numpad1, Numpad2, Numpad3,
+        Numpad4, Numpad5, Numpad6, Numpad7, Numpad8, Numpad9, NumpadAdd, NumpadBackspace,
+        NumpadClear, NumpadClearEntry, NumpadComma, NumpadDecimal, NumpadDivide, NumpadEnter, NumpadEqual, NumpadHash,
+        NumpadMemoryAdd, NumpadMemoryClear, NumpadMemoryRecall, NumpadMemoryStore, NumpadMemorySubtract, NumpadMultiply, NumpadParenLeft, NumpadParenRight,
+        NumpadStar, NumpadSubtract, Escape, Fn, FnLock, PrintScreen, ScrollLock, Pause,
+        BrowserBack, BrowserFavorites, BrowserForward, BrowserHome, BrowserRefresh, BrowserSearch, BrowserStop, Eject,
+        LaunchApp1, LaunchApp2, LaunchMail, MediaPlayPause, MediaSelect, MediaStop, MediaTrackNext, MediaTrackPrevious,
+        Power, Sleep, AudioVolumeDown, AudioVolumeMute, AudioVolumeUp, WakeUp, Meta, Hyper,
+        Turbo, Abort, Resume, Suspend, Again, Copy, Cut, Find,
+        Open, Paste, Props, Select, Undo, Hiragana, Katakana, F1,
+        F2, F3, F4, F5, F6, F7, F8, F9,
+        F10, F11, F12, F13, F14, F15, F16, F17,
+        F18, F19, F20, F21, F22, F23, F24, F25,
+        F26, F27, F28, F29, F30, F31, F32, F33,
+        F34, F35
+    ]
+};
+
+const GAMEPAD_BUTTONS: [GamepadButton; 17] = {
+    use GamepadButton::*;
+    [
+        South, East, North, West, LeftBumper, RightBumper, LeftTrigger, RightTrigger, Select, Start, Mode,
+        LeftStick, RightStick, DPadUp, DPadDown, DPadLeft, DPadRight,
+    ]
+};
+
+/// Written the same way it's parsed, eg. `Key(Space)`, `Mouse(Left)`, `Mouse(Other(4))` or `Gamepad(South)`
+impl fmt::Display for Binding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Binding::Key(key) => write!(f, "Key({:?})", key),
+            Binding::Mouse(button) => write!(f, "Mouse({:?})", button),
+            Binding::Gamepad(button) => write!(f, "Gamepad({:?})", button),
+        }
+    }
+}
+
+impl FromStr for Binding {
+    type Err = io::Error;
+
+    fn from_str(s: &str) -> io::Result<Self> {
+        let unknown = || invalid(format!("Unknown binding `{}`", s));
+        let (device, name) = s.trim().strip_suffix(')').and_then(|s| s.split_once('(')).ok_or_else(unknown)?;
+        let name = name.trim();
+        let named = |debug: &dyn fmt::Debug| format!("{:?}", debug) == name;
+
+        match device.trim() {
+            "Key" => KEY_CODES.iter().find(|key| named(key)).map(|key| Binding::Key(*key)),
+            "Mouse" => match name {
+                "Left" => Some(MouseButton::Left),
+                "Right" => Some(MouseButton::Right),
+                "Middle" => Some(MouseButton::Middle),
+                "Back" => Some(MouseButton::Back),
+                "Forward" => Some(MouseButton::Forward),
+                _ => name
+                    .strip_prefix("Other(")
+                    .and_then(|n| n.strip_suffix(')'))
+                    .and_then(|n| n.trim().parse().ok())
+                    .map(MouseButton::Other),
+            }
+            .map(Binding::Mouse),
+            "Gamepad" => GAMEPAD_BUTTONS.iter().find(|button| named(button)).map(|button| Binding::Gamepad(*button)),
+            _ => None,
+        }
+        .ok_or_else(unknown)
+    }
+}
+
+/// Maps action names to the [Binding]s that trigger them, an action is down while any of its bindings is. <br>
+/// Hand it to the config with `Config::builder().with_actions(..)` and change it at runtime through [Input::actions_mut](crate::Input::actions_mut).
+///
+/// Bindings are saved as plain text, one action per line:
+///```text
+/// # Lines starting with # are ignored
+/// jump = Key(Space), Gamepad(South)
+/// fire = Mouse(Left), Key(ControlLeft)
+/// # Names that would read differently are quoted, with \\, \" and \n escapes
+/// "move = left" = Key(KeyA)
+/// ```
+///```no_run
+/// use graphicility::{ActionMap, Binding, Config, GamepadButton, KeyCode};
+/// let actions = ActionMap::new()
+///     .with("jump", [Binding::Key(KeyCode::Space), Binding::Gamepad(GamepadButton::South)]);
+/// let config = Config::builder().with_actions(actions).build();
+/// graphicility::run_with(config, |ctx| {
+///     if ctx.input().action_pressed("jump") {
+///         // ...
+///     }
+/// });
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ActionMap {
+    actions: BTreeMap<String, Vec<Binding>>,
+}
+
+impl ActionMap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add `bindings` to `action`, for setting up the defaults
+    pub fn with(mut self, action: impl Into<String>, bindings: impl IntoIterator<Item = Binding>) -> Self {
+        let action = action.into();
+        for binding in bindings {
+            self.bind(action.clone(), binding);
+        }
+        self
+    }
+
+    /// Add a binding to `action`, creating the action if it doesn't exist yet
+    pub fn bind(&mut self, action: impl Into<String>, binding: Binding) {
+        let bindings = self.actions.entry(action.into()).or_default();
+        if !bindings.contains(&binding) {
+            bindings.push(binding);
+        }
+    }
+
+    /// Remove a binding from `action`, the action stays around even without bindings
+    pub fn unbind(&mut self, action: &str, binding: Binding) {
+        if let Some(bindings) = self.actions.get_mut(action) {
+            bindings.retain(|b| *b != binding);
+        }
+    }
+
+    /// Replace every binding of `action`, eg. after the player picked a new key in the settings
+    pub fn rebind(&mut self, action: impl Into<String>, bindings: impl IntoIterator<Item = Binding>) {
+        let mut unique = Vec::new();
+        for binding in bindings {
+            if !unique.contains(&binding) {
+                unique.push(binding);
+            }
+        }
+        self.actions.insert(action.into(), unique);
+    }
+
+    /// Remove the action and all of its bindings
+    pub fn remove(&mut self, action: &str) {
+        self.actions.remove(action);
+    }
+
+    /// Returns the bindings of `action`, empty if it doesn't exist
+    pub fn bindings(&self, action: &str) -> &[Binding] {
+        self.actions.get(action).map_or(&[], Vec::as_slice)
+    }
+
+    /// Returns the names of all actions in alphabetical order
+    pub fn actions(&self) -> impl Iterator<Item = &str> {
+        self.actions.keys().map(String::as_str)
+    }
+
+    /// Parse bindings written by [ActionMap::save] or by hand
+    pub fn parse(text: &str) -> io::Result<Self> {
+        let mut map = Self::new();
+        for line in text.lines().map(str::trim) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (action, bindings) = split_action(line)?;
+            let bindings = bindings
+                .split(',')
+                .filter(|b| !b.trim().is_empty())
+                .map(str::parse)
+                .collect::<io::Result<Vec<Binding>>>()?;
+            map.rebind(action, bindings);
+        }
+        Ok(map)
+    }
+
+    /// Load bindings from a file written by [ActionMap::save]
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        Self::parse(&std::fs::read_to_string(path)?)
+    }
+
+    /// Save the bindings to a file, overwriting it if it exists
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        std::fs::write(path, self.to_string())
+    }
+}
+
+impl fmt::Display for ActionMap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (action, bindings) in &self.actions {
+            write_action(f, action)?;
+            write!(f, " =")?;
+            for (n, binding) in bindings.iter().enumerate() {
+                write!(f, "{} {}", if n == 0 { "" } else { "," }, binding)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// Quote names that wouldn't come back the same from [split_action]
+fn write_action(f: &mut fmt::Formatter<'_>, action: &str) -> fmt::Result {
+    let plain = !action.is_empty()
+        && action.trim() == action
+        && !action.starts_with(['#', '"'])
+        && !action.contains(|c: char| c == '=' || c.is_control());
+    if plain {
+        return f.write_str(action);
+    }
+
+    f.write_str("\"")?;
+    for c in action.chars() {
+        match c {
+            '\\' => f.write_str("\\\\")?,
+            '"' => f.write_str("\\\"")?,
+            '\n' => f.write_str("\\n")?,
+            '\r' => f.write_str("\\r")?,
+            '\t' => f.write_str("\\t")?,
+            c => write!(f, "{}", c)?,
+        }
+    }
+    f.write_str("\"")
+}
+
+/// Split a line into the action name and the text after the `=`
+fn split_action(line: &str) -> io::Result<(String, &str)> {
+    let malformed = || invalid(format!("Expected `action = bindings`, got `{}`", line));
+
+    let Some(quoted) = line.strip_prefix('"') else {
+        let (action, bindings) = line.split_once('=').ok_or_else(malformed)?;
+        let action = action.trim();
+        if action.is_empty() {
+            return Err(invalid(format!("Missing action name in `{}`", line)));
+        }
+        return Ok((action.to_string(), bindings));
+    };
+
+    let mut action = String::new();
+    let mut chars = quoted.char_indices();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '"' => {
+                let bindings = quoted[i + 1..].trim_start().strip_prefix('=').ok_or_else(malformed)?;
+                return Ok((action, bindings));
+            }
+            '\\' => action.push(match chars.next() {
+                Some((_, 'n')) => '\n',
+                Some((_, 'r')) => '\r',
+                Some((_, 't')) => '\t',
+                Some((_, c @ ('\\' | '"'))) => c,
+                _ => return Err(invalid(format!("Unknown escape in `{}`", line))),
+            }),
+            c => action.push(c),
+        }
+    }
+    Err(invalid(format!("Missing closing quote in `{}`", line)))
+}
+
+fn invalid(msg: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trip(binding: Binding) {
+        let text = binding.to_string();
+        assert_eq!(text.parse::<Binding>().unwrap(), binding, "{}", text);
+    }
+
+    #[test]
+    fn every_binding_round_trips() {
+        for key in KEY_CODES {
+            round_trip(Binding::Key(*key));
+        }
+        for button in GAMEPAD_BUTTONS {
+            round_trip(Binding::Gamepad(button));
+        }
+        let mouse = [MouseButton::Left, MouseButton::Right, MouseButton::Middle, MouseButton::Back, MouseButton::Forward];
+        for button in mouse.into_iter().chain([0, 7, u16::MAX].map(MouseButton::Other)) {
+            round_trip(Binding::Mouse(button));
+        }
+    }
+
+    #[test]
+    fn key_names_are_unique() {
+        let names: std::collections::HashSet<String> = KEY_CODES.iter().map(|key| format!("{:?}", key)).collect();
+        assert_eq!(names.len(), KEY_CODES.len());
+    }
+
+    #[test]
+    fn bad_bindings() {
+        for text in ["Key(Nope)", "Key Space", "Keyboard(Space)", "Mouse(Other(-1))", "Mouse(Other(x))", "Gamepad()", ""] {
+            assert!(text.parse::<Binding>().is_err(), "{}", text);
+        }
+        assert_eq!(" Key( F35 ) ".parse::<Binding>().unwrap(), Binding::Key(KeyCode::F35));
+    }
+
+    #[test]
+    fn action_map_round_trips() {
+        let names = [
+            "jump", "move left", "a=b", "=", "#not a comment", "\"quoted\"", " padded ", "tab\tand\nnew line\r",
+            "back\\slash", "", "\\n", "ünïcødé", "\u{3000}wide space", "trailing quote\"",
+        ];
+        let mut map = ActionMap::new().with("fire", [Binding::Mouse(MouseButton::Left), Binding::Key(KeyCode::KeyF)]);
+        for (n, name) in names.iter().enumerate() {
+            map.rebind(*name, [Binding::Key(KEY_CODES[n * 13]), Binding::Gamepad(GAMEPAD_BUTTONS[n])]);
+        }
+        map.rebind("unbound", []);
+
+        let text = map.to_string();
+        let parsed = ActionMap::parse(&text).unwrap();
+        assert_eq!(parsed, map, "{}", text);
+        assert_eq!(parsed.to_string(), text);
+    }
+
+    #[test]
+    fn hand_written_files() {
+        let map = ActionMap::parse("# comment\n\n  jump = Key(Space) ,Gamepad(South),\n\"a\\\"b\" =Mouse(Left)\r\n").unwrap();
+        assert_eq!(map.bindings("jump"), &[Binding::Key(KeyCode::Space), Binding::Gamepad(GamepadButton::South)]);
+        assert_eq!(map.bindings("a\"b"), &[Binding::Mouse(MouseButton::Left)]);
+
+        for text in ["jump", "= Key(Space)", "jump = Key(Nope)", "\"open = Key(Space)", "\"a\\x\" = Key(Space)", "\"a\" Key(Space)"] {
+            assert!(ActionMap::parse(text).is_err(), "{}", text);
+        }
+    }
+}
@@ -0,0 +1,485 @@
+use crate::{Color, graphics::{Connectivity, DrawCommand}, vector::Vec2};
+
+/// CPU side RGBA buffer that all the [DrawCommand]s get rasterized into. <br>
+/// Kept separate from the backend so the result can be processed before it's presented.
+pub struct Canvas {
+    pub(super) buffer: Vec<u8>,
+    pub(super) width: u32,
+    pub(super) height: u32,
+}
+
+impl Canvas {
+    pub(crate) fn new(width: u32, height: u32) -> Self {
+        Self {
+            buffer: vec![0; (width * height * 4) as usize],
+            width,
+            height,
+        }
+    }
+    /// Size of the canvas in pixels
+    pub(crate) fn size(&self) -> (u32, u32) {
+        (self.width, self.height)
+    }
+    /// Raw RGBA bytes of the canvas
+    pub(crate) fn frame(&self) -> &[u8] {
+        &self.buffer
+    }
+    /// Rasterize the commands in order
+    pub(crate) fn draw_all(&mut self, commands: &[DrawCommand]) {
+        #[cfg(feature = "parallel")]
+        self.draw_parallel(commands);
+
+        #[cfg(not(feature = "parallel"))]
+        self.draw_serial(commands);
+    }
+    /// Rasterize the commands one by one on the current thread
+    pub(crate) fn draw_serial(&mut self, commands: &[DrawCommand]) {
+        let mut band = Band {
+            buffer: &mut self.buffer,
+            width: self.width,
+            top: 0,
+            bottom: self.height,
+        };
+        for cmd in commands {
+            band.draw(cmd);
+        }
+    }
+}
+
+/// A horizontal strip of the canvas, everything drawn into it is clipped to its rows. <br>
+/// The serial path uses a single band covering the whole canvas, so both paths share the same rasterization code.
+pub(super) struct Band<'a> {
+    /// Pixels of the rows `top..bottom`
+    pub buffer: &'a mut [u8],
+    pub width: u32,
+    pub top: u32,
+    pub bottom: u32,
+}
+
+impl Band<'_> {
+    /// Rasterize a single command
+    pub fn draw(&mut self, cmd: &DrawCommand) {
+        match cmd {
+            DrawCommand::Clear(color) => self.clear(*color),
+            DrawCommand::Pixel { pos, color } => self.plot(pos.x, pos.y, *color),
+            DrawCommand::Circle { center, radius, color } =>  self.draw_circle(*center, *radius, *color),
+            DrawCommand::Rect { pos, size, color } => self.draw_rect(*pos, *size, *color),
+            DrawCommand::Text { pos, text, color, scale } => self.draw_text(*pos, text, *color, *scale),
+            DrawCommand::Line { start, end, color } => self.draw_line(*start, *end, *color),
+            DrawCommand::Triangle { p1, p2, p3, color } => self.draw_triangle(*p1, *p2, *p3, *color),
+            DrawCommand::FloodFill { pos, color, connectivity, tolerance } => {
+                self.flood_fill(*pos, *color, *connectivity, *tolerance)
+            }
+            DrawCommand::Mask { pos, size, alpha, color } => self.draw_mask(*pos, *size, alpha, *color),
+        }
+    }
+    fn draw_text(&mut self, pos: Vec2, text: &str, color: Color, (sx, sy): (u32, u32)) {
+        // Positions stay signed so text hanging off the left or top edge gets cut off instead of moved
+        let (sx, sy) = (sx.min(i32::MAX as u32 / 8) as i32, sy.min(i32::MAX as u32 / 8) as i32);
+        let cursor_y = pos.y;
+        if cursor_y >= self.bottom as i32 || cursor_y.saturating_add(8 * sy) <= self.top as i32 {
+            return;
+        }
+        let mut cursor_x = pos.x;
+
+        for c in text.chars() {
+            if cursor_x >= self.width as i32 {
+                break;
+            }
+            // Glyphs completely left of the canvas are skipped
+            if cursor_x.saturating_add(8 * sx) <= 0 {
+                cursor_x = cursor_x.saturating_add(8 * sx);
+                continue;
+            }
+
+            // Characters the built in sets don't cover draw the fallback glyph
+            let glyph = crate::text::glyph8x8(c);
+
+            // Draw each row of the 8x8 character
+            for (row, byte) in glyph.iter().enumerate() {
+
+                // Draw each pixel in the row
+                for col in 0..8 {
+                    // Check if this bit is set
+                    if (byte & (1 << col)) != 0 {
+                        let px = cursor_x.saturating_add(col * sx);
+                        let py = cursor_y.saturating_add(row as i32 * sy);
+
+                        if sx == 1 && sy == 1 {
+                            self.plot(px, py, color);
+                        } else {
+                            self.draw_rect(Vec2::new(px, py), Vec2::new(sx, sy), color);
+                        }
+                    }
+                }
+            }
+
+            // Move cursor to next character position
+            cursor_x = cursor_x.saturating_add(8 * sx);
+        }
+    }
+
+    fn draw_rect(&mut self, pos: Vec2, size: Vec2, color: Color) {
+        if size.x <= 0 || size.y <= 0 {
+            return;
+        }
+        // Clip in signed coordinates, the part hanging off the left or top edge is cut off
+        let (top, bottom) = (self.top as i64, self.bottom as i64);
+        let start_x = (pos.x as i64).clamp(0, self.width as i64) as u32;
+        let start_y = (pos.y as i64).clamp(top, bottom) as u32;
+        let end_x = (pos.x as i64 + size.x as i64).clamp(0, self.width as i64) as u32;
+        let end_y = (pos.y as i64 + size.y as i64).clamp(top, bottom) as u32;
+
+        if start_x >= end_x || start_y >= end_y {
+            return;
+        }
+
+        let frame = &mut self.buffer;
+        let color_slice = [color.r, color.g, color.b, color.a];
+
+        for row in start_y..end_y {
+            let offset = ((row - self.top) * self.width + start_x) as usize * 4;
+            let row_pixels = (end_x - start_x) as usize;
+
+            let target_row = &mut frame[offset..offset + (row_pixels * 4)];
+            for px in target_row.chunks_exact_mut(4) {
+                px.copy_from_slice(&color_slice);
+            }
+        }
+    }
+    fn draw_line(&mut self, p1: Vec2, p2: Vec2, color: Color) {
+        let mut x0 = p1.x;
+        let mut y0 = p1.y;
+        let x1 = p2.x;
+        let y1 = p2.y;
+
+        let dx = (x1 - x0).abs();
+        let sx = if x0 < x1 { 1 } else { -1 };
+        let dy = -(y1 - y0).abs();
+        let sy = if y0 < y1 { 1 } else { -1 };
+        let mut err = dx + dy;
+
+        loop {
+            self.plot(x0, y0, color);
+            if x0 == x1 && y0 == y1 { break; }
+            let e2 = 2 * err;
+            if e2 >= dy { err += dy; x0 += sx; }
+            if e2 <= dx { err += dx; y0 += sy; }
+        }
+    }
+    fn draw_circle(&mut self, center: Vec2, radius: i32, color: Color) {
+        let mut x = radius;
+        let mut y = 0;
+        let mut err = 0;
+
+        while x >= y {
+            let pts = [
+                (center.x + x, center.y + y),
+                (center.x + y, center.y + x),
+                (center.x - y, center.y + x),
+                (center.x - x, center.y + y),
+                (center.x - x, center.y - y),
+                (center.x - y, center.y - x),
+                (center.x + y, center.y - x),
+                (center.x + x, center.y - y),
+            ];
+            for (px, py) in pts {
+                self.plot(px, py, color);
+            }
+            y += 1;
+            if err <= 0 {
+                err += 2 * y + 1;
+            }
+            if err > 0 {
+                x -= 1;
+                err -= 2 * x + 1;
+            }
+        }
+    }
+    fn draw_triangle(&mut self, p1: Vec2, p2: Vec2, p3: Vec2, color: Color) {
+        self.draw_line(p1, p2, color);
+        self.draw_line(p2, p3, color);
+        self.draw_line(p3, p1, color);
+    }
+
+    /// Needs to see the whole area it fills, so it only works on a band covering the full canvas
+    fn flood_fill(&mut self, pos: Vec2, color: Color, connectivity: Connectivity, tolerance: u8) {
+        if pos.x < 0 || pos.y < 0 {
+            return;
+        }
+        let (x, y) = (pos.x as u32, pos.y as u32);
+        if x >= self.width || y < self.top || y >= self.bottom {
+            return;
+        }
+
+        let width = self.width as i32;
+        let rows = (self.bottom - self.top) as i32;
+        let index = |x: i32, y: i32| (y * width + x) as usize;
+
+        let start = index(x as i32, (y - self.top) as i32) * 4;
+        let target: [u8; 4] = self.buffer[start..start + 4].try_into().unwrap();
+        let fill = [color.r, color.g, color.b, color.a];
+        let matches = |px: &[u8]| {
+            px.iter().zip(target).all(|(a, b)| a.abs_diff(b) <= tolerance)
+        };
+
+        let neighbors: &[(i32, i32)] = match connectivity {
+            Connectivity::Four => &[(1, 0), (-1, 0), (0, 1), (0, -1)],
+            Connectivity::Eight => &[(1, 0), (-1, 0), (0, 1), (0, -1), (1, 1), (1, -1), (-1, 1), (-1, -1)],
+        };
+
+        // Filled pixels might still match the target with a tolerance, so track them separately
+        let mut visited = vec![false; (width * rows) as usize];
+        let mut stack = vec![(x as i32, (y - self.top) as i32)];
+        visited[index(x as i32, (y - self.top) as i32)] = true;
+
+        while let Some((cx, cy)) = stack.pop() {
+            let idx = index(cx, cy) * 4;
+            self.buffer[idx..idx + 4].copy_from_slice(&fill);
+
+            for (dx, dy) in neighbors {
+                let (nx, ny) = (cx + dx, cy + dy);
+                if nx < 0 || ny < 0 || nx >= width || ny >= rows || visited[index(nx, ny)] {
+                    continue;
+                }
+                let n = index(nx, ny) * 4;
+                if matches(&self.buffer[n..n + 4]) {
+                    visited[index(nx, ny)] = true;
+                    stack.push((nx, ny));
+                }
+            }
+        }
+    }
+
+    fn draw_mask(&mut self, pos: Vec2, size: Vec2, alpha: &[u8], color: Color) {
+        if size.x <= 0 || size.y <= 0 {
+            return;
+        }
+        for (row, line) in alpha.chunks_exact(size.x as usize).take(size.y as usize).enumerate() {
+            let y = pos.y + row as i32;
+            if y < self.top as i32 || y >= self.bottom as i32 {
+                continue;
+            }
+            for (col, &a) in line.iter().enumerate() {
+                let x = pos.x + col as i32;
+                if a == 0 || x < 0 || x >= self.width as i32 {
+                    continue;
+                }
+                let weight = a as u32 * color.a as u32 / 255;
+                let idx = (((y as u32 - self.top) * self.width + x as u32) * 4) as usize;
+                let px = &mut self.buffer[idx..idx + 4];
+                for (dst, src) in px.iter_mut().zip([color.r, color.g, color.b]) {
+                    *dst = ((src as u32 * weight + *dst as u32 * (255 - weight)) / 255) as u8;
+                }
+                px[3] = (weight + px[3] as u32 * (255 - weight) / 255) as u8;
+            }
+        }
+    }
+
+    /// [Band::set_pixel] for signed coordinates, anything off the canvas is skipped
+    fn plot(&mut self, x: i32, y: i32, color: Color) {
+        if x >= 0 && y >= 0 {
+            self.set_pixel(x as u32, y as u32, color);
+        }
+    }
+
+    fn set_pixel(&mut self, x: u32, y: u32, color: Color) {
+        if x >= self.width || y < self.top || y >= self.bottom {
+            return;
+        }
+
+        let idx = (((y - self.top) * self.width + x) * 4) as usize;
+        let frame = &mut self.buffer;
+        frame[idx..idx + 4].copy_from_slice(&[color.r, color.g, color.b, color.a]);
+    }
+
+    fn clear(&mut self, color: Color) {
+        let frame = &mut self.buffer;
+        let color_slice = [color.r, color.g, color.b, color.a];
+        for px in frame.chunks_exact_mut(4) {
+            px.copy_from_slice(&color_slice);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `#` is white, anything else black
+    fn canvas(rows: &[&str]) -> Canvas {
+        let mut canvas = Canvas::new(rows[0].len() as u32, rows.len() as u32);
+        let mut commands = vec![DrawCommand::Clear(Color::BLACK)];
+        for (y, row) in rows.iter().enumerate() {
+            for (x, c) in row.chars().enumerate() {
+                if c == '#' {
+                    commands.push(DrawCommand::Pixel { pos: Vec2::new(x as i32, y as i32), color: Color::WHITE });
+                }
+            }
+        }
+        canvas.draw_serial(&commands);
+        canvas
+    }
+
+    fn picture(canvas: &Canvas) -> Vec<String> {
+        canvas
+            .frame()
+            .chunks(canvas.width as usize * 4)
+            .map(|row| {
+                row.chunks(4)
+                    .map(|px| match px {
+                        [0, 0, 0, 255] => '.',
+                        [255, 255, 255, 255] => '#',
+                        [0, 0, 255, 255] => 'o',
+                        _ => '?',
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn fill(canvas: &mut Canvas, x: i32, y: i32, connectivity: Connectivity, tolerance: u8) {
+        canvas.draw_serial(&[DrawCommand::FloodFill { pos: Vec2::new(x, y), color: Color::BLUE, connectivity, tolerance }]);
+    }
+
+    #[test]
+    fn four_way_fills_stop_at_diagonals() {
+        let rows = ["#...", ".#..", "..#.", "...#"];
+
+        let mut four = canvas(&rows);
+        fill(&mut four, 3, 0, Connectivity::Four, 0);
+        assert_eq!(picture(&four), ["#ooo", ".#oo", "..#o", "...#"]);
+
+        let mut eight = canvas(&rows);
+        fill(&mut eight, 3, 0, Connectivity::Eight, 0);
+        assert_eq!(picture(&eight), ["#ooo", "o#oo", "oo#o", "ooo#"]);
+    }
+
+    #[test]
+    fn fills_stay_inside_closed_shapes() {
+        let mut canvas = canvas(&["......", ".####.", ".#..#.", ".####.", "......"]);
+        fill(&mut canvas, 2, 2, Connectivity::Eight, 0);
+        assert_eq!(picture(&canvas), ["......", ".####.", ".#oo#.", ".####.", "......"]);
+
+        // Filling with the color that's already there is a no-op and has to terminate
+        canvas.draw_serial(&[DrawCommand::FloodFill { pos: Vec2::new(0, 0), color: Color::BLACK, connectivity: Connectivity::Four, tolerance: 0 }]);
+        assert_eq!(picture(&canvas), ["......", ".####.", ".#oo#.", ".####.", "......"]);
+
+        // Outside of the canvas nothing happens
+        fill(&mut canvas, 6, 0, Connectivity::Four, 0);
+        fill(&mut canvas, 0, 5, Connectivity::Four, 0);
+        assert_eq!(picture(&canvas), ["......", ".####.", ".#oo#.", ".####.", "......"]);
+    }
+
+    #[test]
+    fn tolerance() {
+        let shades = [0, 5, 10, 11, 3];
+        let mut canvas = Canvas::new(shades.len() as u32, 1);
+        let commands: Vec<_> = shades
+            .iter()
+            .enumerate()
+            .map(|(x, &r)| DrawCommand::Pixel { pos: Vec2::new(x as i32, 0), color: Color::rgb(r, 0, 0) })
+            .collect();
+
+        canvas.draw_serial(&commands);
+        fill(&mut canvas, 0, 0, Connectivity::Four, 0);
+        assert_eq!(picture(&canvas), ["o????"]);
+
+        canvas.draw_serial(&commands);
+        fill(&mut canvas, 0, 0, Connectivity::Four, 10);
+        // The last shade would match but the 11 in front of it blocks the way
+        assert_eq!(picture(&canvas), ["ooo??"]);
+    }
+
+    #[test]
+    fn text_scales_each_axis_on_its_own() {
+        let t = ["######..", "#.##.#..", "..##....", "..##....", "..##....", "..##....", ".####...", "........"];
+        let mut canvas = Canvas::new(16 + 2, 24);
+        canvas.draw_serial(&[
+            DrawCommand::Clear(Color::BLACK),
+            DrawCommand::Text { pos: Vec2::new(1, 0), text: "T".to_string(), color: Color::WHITE, scale: (2, 3) },
+        ]);
+
+        // Every pixel of the glyph becomes a 2x3 block
+        let expected: Vec<String> = t
+            .iter()
+            .flat_map(|row| {
+                let wide: String = row.chars().flat_map(|c| [c, c]).collect();
+                vec![format!(".{}.", wide); 3]
+            })
+            .collect();
+        assert_eq!(picture(&canvas), expected);
+
+        // The second glyph starts 16 pixels after the first, only the empty last column of the first one is left on the canvas
+        canvas.draw_serial(&[
+            DrawCommand::Clear(Color::BLACK),
+            DrawCommand::Text { pos: Vec2::new(-15, 0), text: "TT".to_string(), color: Color::WHITE, scale: (2, 3) },
+        ]);
+        assert_eq!(picture(&canvas)[..4], [".############.....", ".############.....", ".############.....", ".##..####..##....."]);
+    }
+
+    #[test]
+    fn stroke_text() {
+        use winit::dpi::{LogicalSize, PhysicalSize};
+        let mut gfx = crate::Graphics::new(LogicalSize::new(8, 10), PhysicalSize::new(8, 10), crate::ScalingMode::default(), 1);
+        // 7px capitals shrink the L from (8, 0) - (8, 42) - (30, 42) to (1, 0) - (1, 7) - (5, 7)
+        gfx.stroke_text((1, 1), "L", crate::StrokeStyle::sized(7.0), Color::WHITE);
+
+        let mut canvas = Canvas::new(8, 10);
+        canvas.draw_serial(&[DrawCommand::Clear(Color::BLACK)]);
+        canvas.draw_serial(gfx.commands());
+        assert_eq!(
+            picture(&canvas),
+            ["........", "..#.....", "..#.....", "..#.....", "..#.....", "..#.....", "..#.....", "..#.....", "..#####.", "........"]
+        );
+    }
+
+    const MARGIN: i32 = 40;
+
+    /// Drawing at `pos` has to look like drawing at `pos + MARGIN` on a bigger canvas with the margin cut away
+    fn assert_clipped(draw: impl Fn(Vec2) -> DrawCommand, pos: Vec2) {
+        let (width, height) = (24u32, 20u32);
+        let mut small = Canvas::new(width, height);
+        small.draw_serial(&[draw(pos)]);
+
+        let big_width = width + 2 * MARGIN as u32;
+        let mut big = Canvas::new(big_width, height + 2 * MARGIN as u32);
+        big.draw_serial(&[draw(pos + Vec2::new(MARGIN, MARGIN))]);
+
+        for y in 0..height {
+            for x in 0..width {
+                let at = |canvas: &Canvas, x: u32, y: u32, w: u32| {
+                    let i = ((y * w + x) * 4) as usize;
+                    canvas.frame()[i..i + 4].to_vec()
+                };
+                let expected = at(&big, x + MARGIN as u32, y + MARGIN as u32, big_width);
+                assert_eq!(at(&small, x, y, width), expected, "pixel ({}, {}) drawn at {:?}", x, y, pos);
+            }
+        }
+    }
+
+    #[test]
+    fn shapes_are_cut_off_at_the_edges() {
+        let text = |scale| move |pos| DrawCommand::Text { pos, text: "Clip Ég".to_string(), color: Color::WHITE, scale };
+        for pos in [Vec2::new(-3, -2), Vec2::new(-13, 5), Vec2::new(-30, -9), Vec2::new(10, -7), Vec2::new(20, 15)] {
+            assert_clipped(text((1, 1)), pos);
+            assert_clipped(text((2, 3)), pos);
+            assert_clipped(|pos| DrawCommand::Rect { pos, size: Vec2::new(12, 9), color: Color::RED }, pos);
+            assert_clipped(|pos| DrawCommand::Pixel { pos, color: Color::GREEN }, pos);
+            assert_clipped(|pos| DrawCommand::Circle { center: pos, radius: 7, color: Color::BLUE }, pos);
+        }
+    }
+
+    #[test]
+    fn nothing_lands_on_the_edge() {
+        // Used to be moved onto row or column 0
+        let mut canvas = Canvas::new(16, 16);
+        canvas.draw_serial(&[
+            DrawCommand::Pixel { pos: Vec2::new(-1, 4), color: Color::WHITE },
+            DrawCommand::Rect { pos: Vec2::new(3, -20), size: Vec2::new(5, 10), color: Color::WHITE },
+            DrawCommand::Text { pos: Vec2::new(-8, 0), text: "#".to_string(), color: Color::WHITE, scale: (1, 1) },
+            DrawCommand::FloodFill { pos: Vec2::new(-1, -1), color: Color::WHITE, connectivity: Connectivity::Four, tolerance: 0 },
+        ]);
+        assert!(canvas.frame().iter().all(|b| *b == 0));
+    }
+}
@@ -1,3 +1,7 @@
+mod canvas;
 mod pixels;
+mod scaler;
+#[cfg(feature = "parallel")]
+mod tiles;
 
 pub use pixels::PixelsBackend;
@@ -0,0 +1,213 @@
+use pixels::Pixels;
+use pixels::wgpu;
+use pixels::wgpu::util::DeviceExt;
+
+use crate::Color;
+use crate::scaling::{ScalingMode, Viewport};
+
+/// Replacement for the pixels `ScalingRenderer`. <br>
+/// Pixels only knows about integer scaling, this one places the canvas using a [Viewport] so every [ScalingMode] works.
+pub struct ScalingRenderer {
+    vertex_buffer: wgpu::Buffer,
+    uniform_buffer: wgpu::Buffer,
+    bind_group: wgpu::BindGroup,
+    render_pipeline: wgpu::RenderPipeline,
+    clear_color: wgpu::Color,
+    mode: ScalingMode,
+    texture_size: (u32, u32),
+    clip_rect: (u32, u32, u32, u32),
+}
+
+impl ScalingRenderer {
+    pub(crate) fn new(
+        pixels: &Pixels,
+        mode: ScalingMode,
+        letterbox: Color,
+        window_size: (u32, u32),
+    ) -> Self {
+        let device = pixels.device();
+        let texture = pixels.texture();
+        let texture_size = (texture.width(), texture.height());
+        let texture_view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+        let module = device.create_shader_module(wgpu::include_wgsl!("shaders/scale.wgsl"));
+
+        // Nearest neighbor so the pixels stay crisp
+        let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+            label: Some("graphicility_scaling_sampler"),
+            address_mode_u: wgpu::AddressMode::ClampToEdge,
+            address_mode_v: wgpu::AddressMode::ClampToEdge,
+            address_mode_w: wgpu::AddressMode::ClampToEdge,
+            mag_filter: wgpu::FilterMode::Nearest,
+            min_filter: wgpu::FilterMode::Nearest,
+            mipmap_filter: wgpu::FilterMode::Nearest,
+            lod_min_clamp: 0.0,
+            lod_max_clamp: 1.0,
+            compare: None,
+            anisotropy_clamp: 1,
+            border_color: None,
+        });
+
+        // One full screen triangle, the transform moves it into the viewport
+        let vertex_data: [f32; 6] = [-1.0, -1.0, 3.0, -1.0, -1.0, 3.0];
+        let vertex_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("graphicility_scaling_vertex_buffer"),
+            contents: &as_bytes(&vertex_data),
+            usage: wgpu::BufferUsages::VERTEX,
+        });
+        let vertex_buffer_layout = wgpu::VertexBufferLayout {
+            array_stride: (2 * std::mem::size_of::<f32>()) as wgpu::BufferAddress,
+            step_mode: wgpu::VertexStepMode::Vertex,
+            attributes: &[wgpu::VertexAttribute {
+                format: wgpu::VertexFormat::Float32x2,
+                offset: 0,
+                shader_location: 0,
+            }],
+        };
+
+        let viewport = Viewport::new(mode, texture_size, window_size);
+        let transform_bytes = as_bytes(&viewport.transform(window_size));
+        let uniform_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("graphicility_scaling_uniform_buffer"),
+            contents: &transform_bytes,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+        });
+
+        let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("graphicility_scaling_bind_group_layout"),
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Texture {
+                        sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                        multisampled: false,
+                        view_dimension: wgpu::TextureViewDimension::D2,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 2,
+                    visibility: wgpu::ShaderStages::VERTEX,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: wgpu::BufferSize::new(transform_bytes.len() as u64),
+                    },
+                    count: None,
+                },
+            ],
+        });
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("graphicility_scaling_bind_group"),
+            layout: &bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::TextureView(&texture_view),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::Sampler(&sampler),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: uniform_buffer.as_entire_binding(),
+                },
+            ],
+        });
+
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("graphicility_scaling_pipeline_layout"),
+            bind_group_layouts: &[&bind_group_layout],
+            push_constant_ranges: &[],
+        });
+        let render_pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("graphicility_scaling_pipeline"),
+            layout: Some(&pipeline_layout),
+            vertex: wgpu::VertexState {
+                module: &module,
+                entry_point: "vs_main",
+                buffers: &[vertex_buffer_layout],
+            },
+            primitive: wgpu::PrimitiveState::default(),
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState::default(),
+            fragment: Some(wgpu::FragmentState {
+                module: &module,
+                entry_point: "fs_main",
+                targets: &[Some(wgpu::ColorTargetState {
+                    format: pixels.render_texture_format(),
+                    blend: Some(wgpu::BlendState::ALPHA_BLENDING),
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+            }),
+            multiview: None,
+        });
+
+        Self {
+            vertex_buffer,
+            uniform_buffer,
+            bind_group,
+            render_pipeline,
+            clear_color: to_wgpu_color(letterbox),
+            mode,
+            texture_size,
+            clip_rect: viewport.clip_rect(window_size),
+        }
+    }
+
+    /// Recalculate the viewport after the window got resized
+    pub(crate) fn resize(&mut self, queue: &wgpu::Queue, window_size: (u32, u32)) {
+        let viewport = Viewport::new(self.mode, self.texture_size, window_size);
+        queue.write_buffer(&self.uniform_buffer, 0, &as_bytes(&viewport.transform(window_size)));
+        self.clip_rect = viewport.clip_rect(window_size);
+    }
+
+    /// Draw the canvas texture into the render target
+    pub(crate) fn render(&self, encoder: &mut wgpu::CommandEncoder, render_target: &wgpu::TextureView) {
+        let mut rpass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: Some("graphicility_scaling_render_pass"),
+            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                view: render_target,
+                resolve_target: None,
+                ops: wgpu::Operations {
+                    load: wgpu::LoadOp::Clear(self.clear_color),
+                    store: true,
+                },
+            })],
+            depth_stencil_attachment: None,
+        });
+
+        let (x, y, w, h) = self.clip_rect;
+        if w == 0 || h == 0 {
+            return;
+        }
+        rpass.set_pipeline(&self.render_pipeline);
+        rpass.set_bind_group(0, &self.bind_group, &[]);
+        rpass.set_vertex_buffer(0, self.vertex_buffer.slice(..));
+        rpass.set_scissor_rect(x, y, w, h);
+        rpass.draw(0..3, 0..1);
+    }
+}
+
+fn as_bytes(data: &[f32]) -> Vec<u8> {
+    data.iter().flat_map(|f| f.to_ne_bytes()).collect()
+}
+
+fn to_wgpu_color(color: Color) -> wgpu::Color {
+    // The surface is sRGB, so convert the color back to linear space
+    let linear = |c: u8| (c as f64 / 255.0).powf(2.2);
+    wgpu::Color {
+        r: linear(color.r),
+        g: linear(color.g),
+        b: linear(color.b),
+        a: color.a as f64 / 255.0,
+    }
+}
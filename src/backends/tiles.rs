@@ -0,0 +1,197 @@
+use rayon::prelude::*;
+
+use super::canvas::{Band, Canvas};
+use crate::graphics::DrawCommand;
+
+/// Height of a single tile in rows
+const TILE_ROWS: u32 = 64;
+
+impl Canvas {
+    /// Rasterize the commands on the rayon thread pool. <br>
+    /// The canvas is cut into full width tiles and every command is binned into the tiles it touches.
+    /// Each tile then draws its commands in submission order, so the output is identical to [Canvas::draw_serial].
+    pub(crate) fn draw_parallel(&mut self, commands: &[DrawCommand]) {
+        if self.height <= TILE_ROWS {
+            self.draw_serial(commands);
+            return;
+        }
+
+        // Flood fills read pixels from other tiles, everything before one has to be finished first
+        let mut rest = commands;
+        while let Some(barrier) = rest.iter().position(|cmd| matches!(cmd, DrawCommand::FloodFill { .. })) {
+            self.draw_tiles(&rest[..barrier]);
+            self.draw_serial(&rest[barrier..=barrier]);
+            rest = &rest[barrier + 1..];
+        }
+        self.draw_tiles(rest);
+    }
+
+    fn draw_tiles(&mut self, commands: &[DrawCommand]) {
+        if commands.is_empty() {
+            return;
+        }
+
+        let tile_count = self.height.div_ceil(TILE_ROWS) as usize;
+        let mut bins: Vec<Vec<&DrawCommand>> = vec![Vec::new(); tile_count];
+        for cmd in commands {
+            let (first, last) = match command_rows(cmd) {
+                Some((top, bottom)) => {
+                    // Empty or negative sizes end above their first row, the rasterizer draws nothing for those
+                    if bottom < top || bottom < 0 || top >= self.height as i32 {
+                        continue;
+                    }
+                    (
+                        top.max(0) as usize / TILE_ROWS as usize,
+                        (bottom as usize / TILE_ROWS as usize).min(tile_count - 1),
+                    )
+                }
+                None => (0, tile_count - 1),
+            };
+            for bin in &mut bins[first..=last] {
+                bin.push(cmd);
+            }
+        }
+
+        let width = self.width;
+        let height = self.height;
+        self.buffer
+            .par_chunks_mut((TILE_ROWS * width * 4) as usize)
+            .zip(bins.par_iter())
+            .enumerate()
+            .for_each(|(i, (buffer, bin))| {
+                let top = i as u32 * TILE_ROWS;
+                let mut band = Band {
+                    buffer,
+                    width,
+                    top,
+                    bottom: (top + TILE_ROWS).min(height),
+                };
+                for cmd in bin {
+                    band.draw(cmd);
+                }
+            });
+    }
+}
+
+/// First and last row a command can touch, commands that draw nothing end above their first row. <br>
+/// `None` means the command has to be drawn into every tile.
+fn command_rows(cmd: &DrawCommand) -> Option<(i32, i32)> {
+    match cmd {
+        DrawCommand::Clear(_) => None,
+        DrawCommand::Pixel { pos, .. } => Some((pos.y, pos.y)),
+        DrawCommand::Line { start, end, .. } => Some((start.y.min(end.y), start.y.max(end.y))),
+        DrawCommand::Rect { pos, size, .. } => Some((pos.y, pos.y.saturating_add(size.y).saturating_sub(1))),
+        DrawCommand::Circle { center, radius, .. } => {
+            let r = radius.abs();
+            Some((center.y.saturating_sub(r), center.y.saturating_add(r)))
+        }
+        DrawCommand::Triangle { p1, p2, p3, .. } => Some((
+            p1.y.min(p2.y).min(p3.y),
+            p1.y.max(p2.y).max(p3.y),
+        )),
+        // Text layout can end up on any row, keep it simple and send it everywhere
+        DrawCommand::Text { .. } => None,
+        DrawCommand::Mask { pos, size, .. } => Some((pos.y, pos.y.saturating_add(size.y).saturating_sub(1))),
+        // Never binned, see the barrier in draw_parallel
+        DrawCommand::FloodFill { .. } => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graphics::Connectivity;
+    use crate::{Color, Vec2};
+
+    const WIDTH: u32 = 120;
+    /// A few tiles plus a partial one at the bottom
+    const HEIGHT: u32 = TILE_ROWS * 3 + 20;
+
+    fn assert_same(commands: &[DrawCommand]) {
+        let mut serial = Canvas::new(WIDTH, HEIGHT);
+        serial.draw_serial(commands);
+        let mut tiled = Canvas::new(WIDTH, HEIGHT);
+        tiled.draw_parallel(commands);
+
+        if let Some(i) = serial.frame().iter().zip(tiled.frame()).position(|(a, b)| a != b) {
+            let pixel = i as u32 / 4;
+            panic!("tiled output differs at ({}, {}) for {:?}", pixel % WIDTH, pixel / WIDTH, commands);
+        }
+    }
+
+    fn rect(x: i32, y: i32, w: i32, h: i32, color: Color) -> DrawCommand {
+        DrawCommand::Rect { pos: Vec2::new(x, y), size: Vec2::new(w, h), color }
+    }
+    fn mask(x: i32, y: i32, w: i32, h: i32) -> DrawCommand {
+        let alpha = (0..w * h).map(|i| (i * 7 % 256) as u8).collect();
+        DrawCommand::Mask { pos: Vec2::new(x, y), size: Vec2::new(w, h), alpha, color: Color::rgba(200, 40, 90, 0.8) }
+    }
+    fn text(x: i32, y: i32, scale: (u32, u32)) -> DrawCommand {
+        DrawCommand::Text { pos: Vec2::new(x, y), text: "Tiles Ägé ▒".to_string(), color: Color::WHITE, scale }
+    }
+    fn fill(x: i32, y: i32, connectivity: Connectivity) -> DrawCommand {
+        DrawCommand::FloodFill { pos: Vec2::new(x, y), color: Color::BLUE, connectivity, tolerance: 10 }
+    }
+
+    #[test]
+    fn shapes_across_tile_boundaries() {
+        let edge = TILE_ROWS as i32;
+        assert_same(&[
+            DrawCommand::Clear(Color::rgb(10, 20, 30)),
+            rect(5, edge - 3, 50, 7, Color::RED),
+            rect(60, 0, 30, HEIGHT as i32, Color::GREEN),
+            DrawCommand::Line { start: Vec2::new(0, 0), end: Vec2::new(WIDTH as i32 - 1, HEIGHT as i32 - 1), color: Color::YELLOW },
+            DrawCommand::Line { start: Vec2::new(100, edge * 2 + 5), end: Vec2::new(3, edge - 9), color: Color::CYAN },
+            DrawCommand::Circle { center: Vec2::new(60, edge * 2), radius: 40, color: Color::MAGENTA },
+            DrawCommand::Triangle { p1: Vec2::new(10, 10), p2: Vec2::new(110, edge + 20), p3: Vec2::new(30, edge * 3 + 10), color: Color::WHITE },
+            DrawCommand::Pixel { pos: Vec2::new(7, edge), color: Color::RED },
+        ]);
+    }
+
+    #[test]
+    fn negative_and_off_screen_positions() {
+        assert_same(&[
+            rect(-20, -20, 50, 90, Color::RED),
+            rect(10, 200, 40, -100, Color::GREEN),
+            rect(10, -50, 40, 0, Color::GREEN),
+            rect(0, HEIGHT as i32 + 5, 40, 10, Color::BLUE),
+            DrawCommand::Pixel { pos: Vec2::new(-4, -9), color: Color::YELLOW },
+            DrawCommand::Line { start: Vec2::new(-30, -40), end: Vec2::new(50, 400), color: Color::CYAN },
+            DrawCommand::Circle { center: Vec2::new(-10, 70), radius: 30, color: Color::WHITE },
+            DrawCommand::Circle { center: Vec2::new(50, 500), radius: 10, color: Color::WHITE },
+            DrawCommand::Circle { center: Vec2::new(50, 100), radius: -10, color: Color::WHITE },
+            DrawCommand::Triangle { p1: Vec2::new(-50, -50), p2: Vec2::new(200, 60), p3: Vec2::new(40, 300), color: Color::MAGENTA },
+        ]);
+    }
+
+    #[test]
+    fn text_and_masks() {
+        let edge = TILE_ROWS as i32;
+        assert_same(&[
+            text(2, edge - 4, (1, 1)),
+            text(-10, edge * 2 - 7, (2, 3)),
+            text(5, -3, (1, 2)),
+            text(5, HEIGHT as i32 - 4, (3, 3)),
+            mask(20, edge - 10, 30, 25),
+            mask(-5, -5, 12, 12),
+            mask(100, HEIGHT as i32 - 6, 40, 20),
+            mask(10, 200, 10, -100),
+        ]);
+    }
+
+    #[test]
+    fn flood_fills_see_everything_before_them() {
+        let edge = TILE_ROWS as i32;
+        assert_same(&[
+            DrawCommand::Clear(Color::BLACK),
+            DrawCommand::Circle { center: Vec2::new(60, edge), radius: 50, color: Color::WHITE },
+            DrawCommand::Line { start: Vec2::new(0, edge * 2), end: Vec2::new(WIDTH as i32, edge * 2 + 30), color: Color::WHITE },
+            fill(60, edge, Connectivity::Four),
+            rect(0, edge * 2 - 5, 20, 10, Color::RED),
+            fill(5, HEIGHT as i32 - 2, Connectivity::Eight),
+            fill(-3, 10, Connectivity::Four),
+            mask(50, edge - 20, 30, 40),
+            fill(2, 2, Connectivity::Eight),
+        ]);
+    }
+}
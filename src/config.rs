@@ -1,5 +1,8 @@
 #[cfg(feature = "extension")]
 use crate::extensions::Extension;
+use crate::{ActionMap, Color, GamepadProvider, PostEffect, ScalingMode, Upscaler};
+#[cfg(feature = "record")]
+use std::path::PathBuf;
 
 /// Main Config of window <br>
 /// Contains logical and physical sizes  <br>
@@ -11,6 +14,8 @@ pub struct Config {
     pub title: String,
     /// Is window resizeable
     pub resizeable: bool,
+    /// Let the platform's input method editor compose text, see [Input::text_entered](crate::Input::text_entered)
+    pub ime_allowed: bool,
 
     /// Logical resolution Your
     pub logical_width: u32,
@@ -23,13 +28,40 @@ pub struct Config {
     /// Fps
     pub target_fps: Option<u32>,
 
+    /// How the logical canvas is scaled to the window
+    pub scaling_mode: ScalingMode,
+    /// Color of the bars around the canvas when it doesn't cover the whole window
+    pub letterbox_color: Color,
+    /// Pixel art filter used to upscale the canvas before it's presented
+    pub upscaler: Upscaler,
+    /// Effects applied to every frame before it's presented
+    pub post_effects: Vec<PostEffect>,
+    /// Where gamepad input comes from, with the `gamepad` feature it falls back to gilrs
+    pub gamepad_provider: Option<Box<dyn GamepadProvider>>,
+    /// Initial bindings of the named actions, see [Input::action_down](crate::Input::action_down)
+    pub actions: ActionMap,
+
     #[cfg(feature = "extension")]
     pub extensions: Vec<Box<dyn Extension>>,
+
+    /// Every frame gets recorded to this file. See [record](crate::record)
+    #[cfg(feature = "record")]
+    pub record_path: Option<PathBuf>,
+    /// Store the draw commands in the recording too, turned off by `record_input_to`
+    #[cfg(feature = "record")]
+    pub record_commands: bool,
+    /// Drive the [Input](crate::Input) from this recording instead of the keyboard and mouse
+    #[cfg(feature = "record")]
+    pub playback_path: Option<PathBuf>,
+    /// Start the playback over once the recording ends, otherwise the keyboard and mouse take over again
+    #[cfg(feature = "record")]
+    pub playback_looping: bool,
 }
 pub struct ConfigBuilder {
     /// Window Title
     title: Option<String>,
     resizeable: Option<bool>,
+    ime_allowed: Option<bool>,
     /// Logical resolution Your
     logical_width: Option<u32>,
     logical_height: Option<u32>,
@@ -39,9 +71,23 @@ pub struct ConfigBuilder {
     window_height: Option<u32>,
     /// Fps
     target_fps: Option<u32>,
+    scaling_mode: Option<ScalingMode>,
+    letterbox_color: Option<Color>,
+    upscaler: Option<Upscaler>,
+    post_effects: Vec<PostEffect>,
+    gamepad_provider: Option<Box<dyn GamepadProvider>>,
+    actions: Option<ActionMap>,
     /// Extensions
     #[cfg(feature = "extension")]
     extensions: Option<Vec<Box<dyn Extension>>>,
+    #[cfg(feature = "record")]
+    record_path: Option<PathBuf>,
+    #[cfg(feature = "record")]
+    record_commands: Option<bool>,
+    #[cfg(feature = "record")]
+    playback_path: Option<PathBuf>,
+    #[cfg(feature = "record")]
+    playback_looping: Option<bool>,
 }
 impl ConfigBuilder {
     /// Set the window title
@@ -65,11 +111,47 @@ impl ConfigBuilder {
         self.logical_height = Some(size.1);
         self
     }
+    /// Set how the logical canvas is scaled to the window. See [ScalingMode]
+    pub fn with_scaling_mode(mut self, mode: ScalingMode) -> Self {
+        self.scaling_mode = Some(mode);
+        self
+    }
+    /// Set the color of the bars around the canvas. Defaults to black
+    pub fn with_letterbox_color(mut self, color: Color) -> Self {
+        self.letterbox_color = Some(color);
+        self
+    }
+    /// Set the pixel art filter used to upscale the canvas. See [Upscaler]
+    pub fn with_upscaler(mut self, upscaler: Upscaler) -> Self {
+        self.upscaler = Some(upscaler);
+        self
+    }
+    /// Add a post processing effect. Effects run in the order they were added
+    pub fn with_post_effect(mut self, effect: PostEffect) -> Self {
+        self.post_effects.push(effect);
+        self
+    }
     /// Sets the window resizeablity.
     pub fn set_resizeable(mut self, resizeable: bool) -> Self {
         self.resizeable = Some(resizeable);
         self
     }
+    /// Turn on the input method editor for typing languages like Japanese or Chinese. <br>
+    /// Composed text then shows up in [Input::text_entered](crate::Input::text_entered) and [Input::ime_preedit](crate::Input::ime_preedit). Defaults to off
+    pub fn set_ime_allowed(mut self, allowed: bool) -> Self {
+        self.ime_allowed = Some(allowed);
+        self
+    }
+    /// Read gamepads from `provider` instead of the default one. See [GamepadProvider]
+    pub fn with_gamepad_provider(mut self, provider: impl GamepadProvider + 'static) -> Self {
+        self.gamepad_provider = Some(Box::new(provider));
+        self
+    }
+    /// Set the initial action bindings. See [ActionMap]
+    pub fn with_actions(mut self, actions: ActionMap) -> Self {
+        self.actions = Some(actions);
+        self
+    }
     /// Register a single extension
     #[cfg(feature = "extension")]
     pub fn with_extension<Ext: Extension + 'static>(mut self, ext: Ext) -> Self {
@@ -79,19 +161,62 @@ impl ConfigBuilder {
         }
         self
     }
+    /// Record every frame to a file. See [record](crate::record)
+    #[cfg(feature = "record")]
+    pub fn record_to(mut self, path: impl Into<PathBuf>) -> Self {
+        self.record_path = Some(path.into());
+        self.record_commands = Some(true);
+        self
+    }
+    /// Record only the input and delta time of every frame, enough for [play_input_from](Self::play_input_from)
+    #[cfg(feature = "record")]
+    pub fn record_input_to(mut self, path: impl Into<PathBuf>) -> Self {
+        self.record_path = Some(path.into());
+        self.record_commands = Some(false);
+        self
+    }
+    /// Play a recording back through the [Input](crate::Input) instead of reading the keyboard and mouse. <br>
+    /// The recorded delta times are used as well, so the application runs the same way it did while recording.
+    /// Gamepads, window events and the cursor grab stay live.
+    #[cfg(feature = "record")]
+    pub fn play_input_from(mut self, path: impl Into<PathBuf>) -> Self {
+        self.playback_path = Some(path.into());
+        self
+    }
+    /// Start the playback over once it reaches the end. Defaults to off
+    #[cfg(feature = "record")]
+    pub fn set_playback_looping(mut self, looping: bool) -> Self {
+        self.playback_looping = Some(looping);
+        self
+    }
     
     /// Build the Config
     pub fn build(self) -> Config {
         Config {
             title: self.title.unwrap_or("Graphicility Window".to_string()),
             resizeable: self.resizeable.unwrap_or(true),
+            ime_allowed: self.ime_allowed.unwrap_or(false),
             logical_width: self.logical_width.unwrap_or(640),
             logical_height: self.logical_height.unwrap_or(400),
             window_width: self.window_width.unwrap_or(1280),
             window_height: self.window_height.unwrap_or(800),
             target_fps: self.target_fps,
+            scaling_mode: self.scaling_mode.unwrap_or_default(),
+            letterbox_color: self.letterbox_color.unwrap_or(Color::BLACK),
+            upscaler: self.upscaler.unwrap_or_default(),
+            post_effects: self.post_effects,
+            gamepad_provider: self.gamepad_provider,
+            actions: self.actions.unwrap_or_default(),
             #[cfg(feature = "extension")]
-            extensions: self.extensions.unwrap_or(Vec::new()),
+            extensions: self.extensions.unwrap_or_default(),
+            #[cfg(feature = "record")]
+            record_path: self.record_path,
+            #[cfg(feature = "record")]
+            record_commands: self.record_commands.unwrap_or(true),
+            #[cfg(feature = "record")]
+            playback_path: self.playback_path,
+            #[cfg(feature = "record")]
+            playback_looping: self.playback_looping.unwrap_or(false),
         }
     }
 }
@@ -104,10 +229,25 @@ impl Config {
             window_height: None,
             window_width: None,
             resizeable: None,
+            ime_allowed: None,
             title: None,
             target_fps: None,
+            scaling_mode: None,
+            letterbox_color: None,
+            upscaler: None,
+            post_effects: Vec::new(),
+            gamepad_provider: None,
+            actions: None,
             #[cfg(feature = "extension")]
             extensions: None,
+            #[cfg(feature = "record")]
+            record_path: None,
+            #[cfg(feature = "record")]
+            record_commands: None,
+            #[cfg(feature = "record")]
+            playback_path: None,
+            #[cfg(feature = "record")]
+            playback_looping: None,
         }
     }
 }
@@ -117,13 +257,28 @@ impl Default for Config {
         Self {
             title: "Graphicility Window".to_string(),
             resizeable: true,
+            ime_allowed: false,
             logical_width: 640,
             logical_height: 400,
             window_width: 1280,
             window_height: 800,
             target_fps: Some(60),
+            scaling_mode: ScalingMode::default(),
+            letterbox_color: Color::BLACK,
+            upscaler: Upscaler::default(),
+            post_effects: Vec::new(),
+            gamepad_provider: None,
+            actions: ActionMap::default(),
             #[cfg(feature = "extension")]
             extensions: Vec::new(),
+            #[cfg(feature = "record")]
+            record_path: None,
+            #[cfg(feature = "record")]
+            record_commands: true,
+            #[cfg(feature = "record")]
+            playback_path: None,
+            #[cfg(feature = "record")]
+            playback_looping: false,
         }
     }
 }
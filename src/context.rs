@@ -30,9 +30,13 @@ impl FrameContext {
     pub fn input(&self) -> &Input{
         &self.inputs
     }
+    /// Gets a mutable referance to the Input, eg. to rebind actions with [Input::actions_mut]
+    pub fn input_mut(&mut self) -> &mut Input{
+        &mut self.inputs
+    }
     /// Splits the Graphics and Input from the Context
     pub fn split(&mut self) -> (&mut Graphics, &Input){
-        return (&mut self.gfx, &self.inputs);
+        (&mut self.gfx, &self.inputs)
     }
     
 }
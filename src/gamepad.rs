@@ -0,0 +1,408 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+
+/// Identifies a connected gamepad, ids of disconnected gamepads can be handed out again
+pub type GamepadId = usize;
+
+/// Gamepad buttons named by their position on the pad, so `South` is A on Xbox and Cross on PlayStation pads
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "record", derive(serde::Serialize, serde::Deserialize))]
+pub enum GamepadButton {
+    South,
+    East,
+    North,
+    West,
+    LeftBumper,
+    RightBumper,
+    /// Also reported as [GamepadAxis::LeftTrigger] on pads with analog triggers
+    LeftTrigger,
+    /// Also reported as [GamepadAxis::RightTrigger] on pads with analog triggers
+    RightTrigger,
+    Select,
+    Start,
+    /// The button with the vendor logo
+    Mode,
+    LeftStick,
+    RightStick,
+    DPadUp,
+    DPadDown,
+    DPadLeft,
+    DPadRight,
+}
+
+/// Analog inputs of a gamepad
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "record", derive(serde::Serialize, serde::Deserialize))]
+pub enum GamepadAxis {
+    /// -1.0 is left, 1.0 is right
+    LeftStickX,
+    /// -1.0 is down, 1.0 is up
+    LeftStickY,
+    RightStickX,
+    RightStickY,
+    /// 0.0 is released, 1.0 is fully pulled
+    LeftTrigger,
+    RightTrigger,
+}
+
+/// Something that happened on a gamepad, reported by a [GamepadProvider]
+#[derive(Clone, Debug, PartialEq)]
+pub enum GamepadEvent {
+    Connected { id: GamepadId, name: String },
+    Disconnected(GamepadId),
+    ButtonPressed(GamepadId, GamepadButton),
+    ButtonReleased(GamepadId, GamepadButton),
+    AxisChanged(GamepadId, GamepadAxis, f32),
+}
+
+/// Where [Input](crate::Input) gets gamepad events from, set with `Config::builder().with_gamepad_provider(..)`. <br>
+/// With the `gamepad` feature `GilrsGamepads` is used when no other provider is set, [MemoryGamepads] is handy for tests and demos.
+pub trait GamepadProvider {
+    /// Called once at the start of every frame, return everything that happened since the last call
+    fn poll(&mut self) -> Vec<GamepadEvent>;
+}
+
+/// A [GamepadProvider] fed by hand. <br>
+/// Clones share the same queue, so keep one around to push events after the provider was handed to the config.
+#[derive(Clone, Debug, Default)]
+pub struct MemoryGamepads {
+    queue: Arc<Mutex<Vec<GamepadEvent>>>,
+}
+
+impl MemoryGamepads {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Queue an event for the next frame
+    pub fn push(&self, event: GamepadEvent) {
+        self.queue.lock().unwrap_or_else(|err| err.into_inner()).push(event);
+    }
+    pub fn connect(&self, id: GamepadId, name: impl Into<String>) {
+        self.push(GamepadEvent::Connected { id, name: name.into() });
+    }
+    pub fn disconnect(&self, id: GamepadId) {
+        self.push(GamepadEvent::Disconnected(id));
+    }
+    pub fn press(&self, id: GamepadId, button: GamepadButton) {
+        self.push(GamepadEvent::ButtonPressed(id, button));
+    }
+    pub fn release(&self, id: GamepadId, button: GamepadButton) {
+        self.push(GamepadEvent::ButtonReleased(id, button));
+    }
+    pub fn set_axis(&self, id: GamepadId, axis: GamepadAxis, value: f32) {
+        self.push(GamepadEvent::AxisChanged(id, axis, value));
+    }
+}
+
+impl GamepadProvider for MemoryGamepads {
+    fn poll(&mut self) -> Vec<GamepadEvent> {
+        std::mem::take(&mut *self.queue.lock().unwrap_or_else(|err| err.into_inner()))
+    }
+}
+
+/// The default [GamepadProvider], reads real gamepads through [gilrs](https://docs.rs/gilrs)
+#[cfg(feature = "gamepad")]
+pub struct GilrsGamepads {
+    gilrs: gilrs::Gilrs,
+    /// Pads that were already plugged in before the first poll still need a connect event
+    announced: bool,
+}
+
+#[cfg(feature = "gamepad")]
+impl GilrsGamepads {
+    pub fn new() -> Result<Self, Box<gilrs::Error>> {
+        Ok(Self { gilrs: gilrs::Gilrs::new().map_err(Box::new)?, announced: false })
+    }
+}
+
+#[cfg(feature = "gamepad")]
+impl GamepadProvider for GilrsGamepads {
+    fn poll(&mut self) -> Vec<GamepadEvent> {
+        use gilrs::{Axis, Button, EventType};
+
+        let mut events = Vec::new();
+        if !self.announced {
+            self.announced = true;
+            for (id, pad) in self.gilrs.gamepads() {
+                events.push(GamepadEvent::Connected { id: id.into(), name: pad.name().to_string() });
+            }
+        }
+
+        let button = |button: Button| match button {
+            Button::South => Some(GamepadButton::South),
+            Button::East => Some(GamepadButton::East),
+            Button::North => Some(GamepadButton::North),
+            Button::West => Some(GamepadButton::West),
+            Button::LeftTrigger => Some(GamepadButton::LeftBumper),
+            Button::RightTrigger => Some(GamepadButton::RightBumper),
+            Button::LeftTrigger2 => Some(GamepadButton::LeftTrigger),
+            Button::RightTrigger2 => Some(GamepadButton::RightTrigger),
+            Button::Select => Some(GamepadButton::Select),
+            Button::Start => Some(GamepadButton::Start),
+            Button::Mode => Some(GamepadButton::Mode),
+            Button::LeftThumb => Some(GamepadButton::LeftStick),
+            Button::RightThumb => Some(GamepadButton::RightStick),
+            Button::DPadUp => Some(GamepadButton::DPadUp),
+            Button::DPadDown => Some(GamepadButton::DPadDown),
+            Button::DPadLeft => Some(GamepadButton::DPadLeft),
+            Button::DPadRight => Some(GamepadButton::DPadRight),
+            _ => None,
+        };
+
+        while let Some(gilrs::Event { id: pad, event, .. }) = self.gilrs.next_event() {
+            let id: GamepadId = pad.into();
+            let event = match event {
+                EventType::Connected => {
+                    let name = self.gilrs.gamepad(pad).name().to_string();
+                    Some(GamepadEvent::Connected { id, name })
+                }
+                EventType::Disconnected => Some(GamepadEvent::Disconnected(id)),
+                EventType::ButtonPressed(b, _) => button(b).map(|b| GamepadEvent::ButtonPressed(id, b)),
+                EventType::ButtonReleased(b, _) => button(b).map(|b| GamepadEvent::ButtonReleased(id, b)),
+                // Analog triggers come through as buttons with a value
+                EventType::ButtonChanged(Button::LeftTrigger2, value, _) => {
+                    Some(GamepadEvent::AxisChanged(id, GamepadAxis::LeftTrigger, value))
+                }
+                EventType::ButtonChanged(Button::RightTrigger2, value, _) => {
+                    Some(GamepadEvent::AxisChanged(id, GamepadAxis::RightTrigger, value))
+                }
+                EventType::AxisChanged(axis, value, _) => {
+                    let axis = match axis {
+                        Axis::LeftStickX => Some(GamepadAxis::LeftStickX),
+                        Axis::LeftStickY => Some(GamepadAxis::LeftStickY),
+                        Axis::RightStickX => Some(GamepadAxis::RightStickX),
+                        Axis::RightStickY => Some(GamepadAxis::RightStickY),
+                        _ => None,
+                    };
+                    axis.map(|axis| GamepadEvent::AxisChanged(id, axis, value))
+                }
+                _ => None,
+            };
+            events.extend(event);
+        }
+        events
+    }
+}
+
+/// State of a single connected gamepad
+#[derive(Debug, Default)]
+struct Pad {
+    name: String,
+    held: HashSet<GamepadButton>,
+    pressed: HashSet<GamepadButton>,
+    released: HashSet<GamepadButton>,
+    axes: HashMap<GamepadAxis, f32>,
+}
+
+/// How far sticks and triggers have to move before they count, see [Input::set_gamepad_deadzone](crate::Input::set_gamepad_deadzone)
+pub(crate) const DEFAULT_DEADZONE: f32 = 0.1;
+
+/// Gamepad side of [Input](crate::Input)
+pub(crate) struct Gamepads {
+    provider: Option<Box<dyn GamepadProvider>>,
+    pub(crate) deadzone: f32,
+    pads: BTreeMap<GamepadId, Pad>,
+    pub(crate) connected: Vec<GamepadId>,
+    pub(crate) disconnected: Vec<GamepadId>,
+}
+
+impl Gamepads {
+    /// Uses `GilrsGamepads` if there's no provider and the `gamepad` feature is on
+    pub(crate) fn new(provider: Option<Box<dyn GamepadProvider>>) -> Self {
+        #[cfg(feature = "gamepad")]
+        let provider = provider.or_else(|| match GilrsGamepads::new() {
+            Ok(gilrs) => Some(Box::new(gilrs) as Box<dyn GamepadProvider>),
+            Err(err) => {
+                eprintln!("Gamepads are unavailable: {}", err);
+                None
+            }
+        });
+        Self {
+            provider,
+            deadzone: DEFAULT_DEADZONE,
+            pads: BTreeMap::new(),
+            connected: Vec::new(),
+            disconnected: Vec::new(),
+        }
+    }
+
+    pub(crate) fn poll(&mut self) {
+        let Some(provider) = &mut self.provider else { return };
+        for event in provider.poll() {
+            self.apply(event);
+        }
+    }
+
+    pub(crate) fn apply(&mut self, event: GamepadEvent) {
+        match event {
+            GamepadEvent::Connected { id, name } => {
+                self.pads.insert(id, Pad { name, ..Pad::default() });
+                self.connected.push(id);
+            }
+            GamepadEvent::Disconnected(id) => {
+                if self.pads.remove(&id).is_some() {
+                    self.disconnected.push(id);
+                }
+            }
+            GamepadEvent::ButtonPressed(id, button) => {
+                if let Some(pad) = self.pads.get_mut(&id)
+                    && pad.held.insert(button)
+                {
+                    pad.pressed.insert(button);
+                }
+            }
+            GamepadEvent::ButtonReleased(id, button) => {
+                if let Some(pad) = self.pads.get_mut(&id)
+                    && pad.held.remove(&button)
+                {
+                    pad.released.insert(button);
+                }
+            }
+            GamepadEvent::AxisChanged(id, axis, value) => {
+                if let Some(pad) = self.pads.get_mut(&id) {
+                    pad.axes.insert(axis, value);
+                }
+            }
+        }
+    }
+
+    pub(crate) fn reset_transient_state(&mut self) {
+        for pad in self.pads.values_mut() {
+            pad.pressed.clear();
+            pad.released.clear();
+        }
+        self.connected.clear();
+        self.disconnected.clear();
+    }
+
+    pub(crate) fn ids(&self) -> Vec<GamepadId> {
+        self.pads.keys().copied().collect()
+    }
+    pub(crate) fn name(&self, id: GamepadId) -> Option<&str> {
+        self.pads.get(&id).map(|pad| pad.name.as_str())
+    }
+    pub(crate) fn held(&self, id: GamepadId, button: GamepadButton) -> bool {
+        self.pads.get(&id).is_some_and(|pad| pad.held.contains(&button))
+    }
+    pub(crate) fn pressed(&self, id: GamepadId, button: GamepadButton) -> bool {
+        self.pads.get(&id).is_some_and(|pad| pad.pressed.contains(&button))
+    }
+    pub(crate) fn released(&self, id: GamepadId, button: GamepadButton) -> bool {
+        self.pads.get(&id).is_some_and(|pad| pad.released.contains(&button))
+    }
+    /// A button pressed this frame on any gamepad
+    pub(crate) fn pressed_any(&self) -> Option<GamepadButton> {
+        self.pads.values().find_map(|pad| pad.pressed.iter().next().copied())
+    }
+    /// Values inside the deadzone are 0.0, the rest is stretched so it still reaches 1.0
+    pub(crate) fn axis(&self, id: GamepadId, axis: GamepadAxis) -> f32 {
+        let value = self.pads.get(&id).and_then(|pad| pad.axes.get(&axis)).copied().unwrap_or(0.0);
+        if value.abs() <= self.deadzone {
+            return 0.0;
+        }
+        (value.abs() - self.deadzone) / (1.0 - self.deadzone) * value.signum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ActionMap, Input};
+
+    fn input_with(pads: &MemoryGamepads) -> Input {
+        Input::new(Some(Box::new(pads.clone())), ActionMap::default())
+    }
+
+    /// What the runtime does around a frame
+    fn frame(input: &mut Input) {
+        input.reset_transient_state();
+        input.gamepads.poll();
+    }
+
+    #[test]
+    fn connect_and_disconnect() {
+        let pads = MemoryGamepads::new();
+        let mut input = input_with(&pads);
+        pads.connect(3, "Pad A");
+        pads.connect(1, "Pad B");
+        frame(&mut input);
+        assert_eq!(input.gamepads(), vec![1, 3]);
+        assert_eq!(input.gamepads_connected(), &[3, 1]);
+        assert_eq!(input.gamepad_name(3), Some("Pad A"));
+
+        pads.disconnect(3);
+        pads.disconnect(7);
+        frame(&mut input);
+        assert_eq!(input.gamepads(), vec![1]);
+        assert!(input.gamepads_connected().is_empty());
+        assert_eq!(input.gamepads_disconnected(), &[3]);
+        assert_eq!(input.gamepad_name(3), None);
+
+        frame(&mut input);
+        assert!(input.gamepads_disconnected().is_empty());
+    }
+
+    #[test]
+    fn button_edges() {
+        let pads = MemoryGamepads::new();
+        let mut input = input_with(&pads);
+        pads.connect(0, "Pad");
+        pads.press(0, GamepadButton::South);
+        frame(&mut input);
+        assert!(input.gamepad_pressed(0, GamepadButton::South));
+        assert!(input.gamepad_down(0, GamepadButton::South));
+        assert!(!input.gamepad_released(0, GamepadButton::South));
+
+        // Repeated presses while held aren't new presses
+        pads.press(0, GamepadButton::South);
+        frame(&mut input);
+        assert!(!input.gamepad_pressed(0, GamepadButton::South));
+        assert!(input.gamepad_down(0, GamepadButton::South));
+
+        pads.release(0, GamepadButton::South);
+        frame(&mut input);
+        assert!(input.gamepad_released(0, GamepadButton::South));
+        assert!(!input.gamepad_down(0, GamepadButton::South));
+
+        frame(&mut input);
+        assert!(!input.gamepad_released(0, GamepadButton::South));
+
+        // Pressed and released within one frame still shows both edges
+        pads.press(0, GamepadButton::Start);
+        pads.release(0, GamepadButton::Start);
+        frame(&mut input);
+        assert!(input.gamepad_pressed(0, GamepadButton::Start));
+        assert!(input.gamepad_released(0, GamepadButton::Start));
+        assert!(!input.gamepad_down(0, GamepadButton::Start));
+
+        // Buttons of unknown pads are ignored, the state goes away with the pad
+        pads.press(5, GamepadButton::East);
+        pads.press(0, GamepadButton::East);
+        pads.disconnect(0);
+        frame(&mut input);
+        assert!(!input.gamepad_down(5, GamepadButton::East));
+        assert!(!input.gamepad_down(0, GamepadButton::East));
+    }
+
+    #[test]
+    fn axis_deadzone() {
+        let pads = MemoryGamepads::new();
+        let mut input = input_with(&pads);
+        pads.connect(0, "Pad");
+        pads.set_axis(0, GamepadAxis::LeftStickX, 0.05);
+        pads.set_axis(0, GamepadAxis::LeftStickY, -1.0);
+        pads.set_axis(0, GamepadAxis::RightStickX, 0.55);
+        frame(&mut input);
+        assert_eq!(input.gamepad_axis(0, GamepadAxis::LeftStickX), 0.0);
+        assert_eq!(input.gamepad_axis(0, GamepadAxis::LeftStickY), -1.0);
+        assert!((input.gamepad_axis(0, GamepadAxis::RightStickX) - 0.5).abs() < 1e-6);
+        assert_eq!(input.gamepad_axis(0, GamepadAxis::RightTrigger), 0.0);
+        assert_eq!(input.gamepad_axis(1, GamepadAxis::LeftStickX), 0.0);
+
+        input.set_gamepad_deadzone(0.0);
+        assert_eq!(input.gamepad_axis(0, GamepadAxis::LeftStickX), 0.05);
+        input.set_gamepad_deadzone(0.6);
+        assert_eq!(input.gamepad_axis(0, GamepadAxis::RightStickX), 0.0);
+        assert_eq!(input.gamepad_axis(0, GamepadAxis::LeftStickY), -1.0);
+    }
+}
@@ -1,8 +1,13 @@
 use winit::dpi::{LogicalSize, PhysicalSize};
 
 use crate::Color;
-use crate::vector::Vec2;
+use crate::text::{self, lines, BitmapFont, CharGrid, HAlign, StrokeStyle, TextAlign, TextMask, TextStyle, VAlign};
+use crate::scaling::{ScalingMode, Viewport};
+use crate::vector::{Rect, Vec2};
 
+/// A single drawing operation. Every [Graphics] call pushes one of these, they get rasterized in order at the end of the frame.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "record", derive(serde::Serialize, serde::Deserialize))]
 pub enum DrawCommand {
     Clear(Color),
     Pixel {
@@ -30,38 +35,162 @@ pub enum DrawCommand {
         p3: Vec2,
         color: Color,
     },
+    /// Text in the built in 8x8 font, every glyph pixel is drawn as a `scale.0` by `scale.1` block
     Text {
         pos: Vec2,
         text: String,
         color: Color,
+        scale: (u32, u32),
     },
+    FloodFill {
+        pos: Vec2,
+        color: Color,
+        connectivity: Connectivity,
+        tolerance: u8,
+    },
+    /// Blends `color` into a `size` area, weighted by one `alpha` byte per pixel (row by row)
+    Mask {
+        pos: Vec2,
+        size: Vec2,
+        alpha: Vec<u8>,
+        color: Color,
+    },
+}
+
+/// Which neighbors a flood fill spreads to
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "record", derive(serde::Serialize, serde::Deserialize))]
+pub enum Connectivity {
+    /// Up, down, left and right
+    #[default]
+    Four,
+    /// Also spreads diagonally
+    Eight,
+}
+
+/// The font text is measured and drawn with right now
+enum TextFont<'a> {
+    Builtin,
+    Bitmap(&'a BitmapFont),
+    #[cfg(feature = "ttf")]
+    TrueType(&'a crate::Font, f32),
 }
 
 /// This is the Main Drawing interface.
 pub struct Graphics {
     commands: Vec<DrawCommand>,
+    /// Layer of each command in `commands`
+    command_layers: Vec<i32>,
+    /// Layer new commands are put on
+    layer: i32,
+    /// Font used by [Graphics::text], `None` is the built in 8x8 font
+    font: Option<BitmapFont>,
+    /// TrueType font and pixel size set by [Graphics::with_font], takes over from `font` while it's set
+    #[cfg(feature = "ttf")]
+    ttf_font: Option<(crate::Font, f32)>,
+    text_style: TextStyle,
+    /// Commands of the previous frame, used to detect frames that didn't change
+    last_commands: Vec<DrawCommand>,
+    /// Set by `end_frame` when the commands differ from the previous frame
+    changed: bool,
+    /// Treat this frame as changed even if the commands are the same
+    redraw_requested: bool,
+    /// Counts the frames that changed, lets the backend know whether its canvas is still current
+    generation: u64,
     logic_width: u32,
     logic_height: u32,
 
     pub(crate) window_width: u32,
     pub(crate) window_height: u32,
+    pub(crate) scaling_mode: ScalingMode,
+    /// How many times bigger the presented canvas is after upscaling
+    pub(crate) upscale_factor: u32,
 }
 
 impl Graphics {
     /// Create a new Graphics Struct
-    pub(crate) fn new(logic_size: LogicalSize<u32>, phy_size: PhysicalSize<u32>) -> Self {
+    pub(crate) fn new(
+        logic_size: LogicalSize<u32>,
+        phy_size: PhysicalSize<u32>,
+        scaling_mode: ScalingMode,
+        upscale_factor: u32,
+    ) -> Self {
         Graphics {
             commands: Vec::with_capacity(128),
+            command_layers: Vec::with_capacity(128),
+            layer: 0,
+            font: None,
+            #[cfg(feature = "ttf")]
+            ttf_font: None,
+            text_style: TextStyle::default(),
+            last_commands: Vec::with_capacity(128),
+            changed: false,
+            redraw_requested: false,
+            generation: 0,
             logic_height: logic_size.height,
             logic_width: logic_size.width,
             window_height: phy_size.height,
             window_width: phy_size.width,
+            scaling_mode,
+            upscale_factor,
         }
     }
     /// Clear commands at the start of each frame
     /// This should be called internally by the runtime at the before rendering
     pub(crate) fn begin_frame(&mut self) {
+        std::mem::swap(&mut self.commands, &mut self.last_commands);
         self.commands.clear();
+        self.command_layers.clear();
+        self.layer = 0;
+    }
+    /// Sort the commands back to front once everything for this frame has been drawn. <br>
+    /// The sort is stable, so commands on the same layer keep their submission order.
+    /// Also the only place the commands get compared with the previous frame.
+    pub(crate) fn end_frame(&mut self) {
+        if !self.command_layers.is_sorted() {
+            let mut layered: Vec<(i32, DrawCommand)> = self.command_layers.drain(..).zip(self.commands.drain(..)).collect();
+            layered.sort_by_key(|(layer, _)| *layer);
+            (self.command_layers, self.commands) = layered.into_iter().unzip();
+        }
+        self.changed = std::mem::take(&mut self.redraw_requested) || self.commands != self.last_commands;
+        if self.changed {
+            self.generation += 1;
+        }
+    }
+    fn push(&mut self, cmd: DrawCommand) {
+        self.commands.push(cmd);
+        self.command_layers.push(self.layer);
+    }
+    /// Put everything drawn after this call on layer `n`. <br>
+    /// Layers are drawn from the lowest to the highest no matter in which order they were submitted,
+    /// so a HUD on a high layer always ends up on top. **Every frame starts on layer 0**
+    pub fn layer(&mut self, n: i32) {
+        self.layer = n;
+    }
+    /// The layer new commands are put on
+    pub fn current_layer(&self) -> i32 {
+        self.layer
+    }
+    /// Draw on layer `n` inside `f`, then go back to the previous layer. <br>
+    /// Handy for extensions that shouldn't change the layer the app is drawing on.
+    pub fn with_layer(&mut self, n: i32, f: impl FnOnce(&mut Self)) {
+        let previous = self.layer;
+        self.layer = n;
+        f(self);
+        self.layer = previous;
+    }
+    /// Rasterize this frame even if it has the same commands as the last one. <br>
+    /// Frames that didn't change are skipped before post processing, so time based effects need this to keep moving.
+    pub fn request_redraw(&mut self) {
+        self.redraw_requested = true;
+    }
+    /// Returns true if this frame's commands differ from the previous frame
+    pub(crate) fn frame_changed(&self) -> bool {
+        self.changed
+    }
+    /// Changes whenever [Graphics::frame_changed] is true, the same value means the same commands
+    pub(crate) fn generation(&self) -> u64 {
+        self.generation
     }
     /// Get the list of draw commands for this frame
     pub(crate) fn commands(&self) -> &[DrawCommand] {
@@ -75,10 +204,41 @@ impl Graphics {
     pub fn window_size(&self) -> (u32, u32) {
         (self.window_width, self.window_height)
     }
+    /// Converts a physical window position into logical canvas coordinates. <br>
+    /// Returns `None` if the position is outside of the canvas (eg. on the letterbox bars).
+    pub fn window_to_logical(&self, pos: (f32, f32)) -> Option<(f32, f32)> {
+        let (lw, lh) = self.logical_size();
+        let vp = self.viewport();
+
+        let x = (pos.0 - vp.x) * lw as f32 / vp.width;
+        let y = (pos.1 - vp.y) * lh as f32 / vp.height;
+
+        if x < 0.0 || y < 0.0 || x >= lw as f32 || y >= lh as f32 {
+            return None;
+        }
+        Some((x, y))
+    }
+    /// Converts logical canvas coordinates into a physical window position. <br>
+    /// The result may be outside of the window when using [ScalingMode::Fill].
+    pub fn logical_to_window(&self, pos: (f32, f32)) -> (f32, f32) {
+        let (lw, lh) = self.logical_size();
+        let vp = self.viewport();
+
+        (
+            vp.x + pos.0 * vp.width / lw as f32,
+            vp.y + pos.1 * vp.height / lh as f32,
+        )
+    }
+    fn viewport(&self) -> Viewport {
+        // The renderer places the upscaled canvas, so the viewport has to be worked out the same way
+        let (lw, lh) = self.logical_size();
+        let presented = (lw * self.upscale_factor, lh * self.upscale_factor);
+        Viewport::new(self.scaling_mode, presented, self.window_size())
+    }
     /// Clear the screen with a color  
     /// You should call this at the start of each frame to clear the previous frame's drawings
     pub fn clear(&mut self, color: Color) {
-        self.commands.push(DrawCommand::Clear(color));
+        self.push(DrawCommand::Clear(color));
     }
     /// Draw a single pixel to a given Point as a [Vec2]
     pub fn pixel(&mut self, pos: impl Into<Vec2>, color: Color) {
@@ -88,11 +248,11 @@ impl Graphics {
         // Internal guard check
         if p.x < 0 || p.y < 0 { return; }
 
-        self.commands.push(DrawCommand::Pixel { pos: p, color });
+        self.push(DrawCommand::Pixel { pos: p, color });
     }
     /// Draw a line between 2 Points.
     pub fn line(&mut self, start: impl Into<Vec2>, end: impl Into<Vec2>, color: Color) {
-        self.commands.push(DrawCommand::Line {
+        self.push(DrawCommand::Line {
             start: start.into(),
             end: end.into(),
             color,
@@ -106,26 +266,616 @@ impl Graphics {
         // We can still do your negative clipping logic here easily
         if s.x <= 0 || s.y <= 0 { return; }
 
-        self.commands.push(DrawCommand::Rect { pos: p, size: s, color });
+        self.push(DrawCommand::Rect { pos: p, size: s, color });
     }
     pub fn circle(&mut self, center: impl Into<Vec2>, radius: i32, color : Color){
-        self.commands.push(DrawCommand::Circle { center: center.into(), radius,  color});
+        self.push(DrawCommand::Circle { center: center.into(), radius,  color});
     }
     /// Draw a Hollow Triangle using the given 3 points.
     pub fn triangle(&mut self, p1: impl Into<Vec2>, p2: impl Into<Vec2>, p3: impl Into<Vec2>, color: Color) {
-        self.commands.push(DrawCommand::Triangle {
+        self.push(DrawCommand::Triangle {
             p1: p1.into(),
             p2: p2.into(),
             p3: p3.into(),
             color,
         });
     }
-    /// Draw text at `pos` with the specified color using the internal 8x8 bitmap font
+    /// Fill the area around `pos` with `color`, like a paint bucket. <br>
+    /// Works on what has been drawn before it, so call it after the shapes it should fill. Lower layers count as drawn before.
+    pub fn flood_fill(&mut self, pos: impl Into<Vec2>, color: Color) {
+        self.flood_fill_with(pos, color, Connectivity::Four, 0);
+    }
+    /// Same as [Self::flood_fill], also spreading to pixels whose channels are at most `tolerance` away from the starting color.
+    pub fn flood_fill_with(
+        &mut self,
+        pos: impl Into<Vec2>,
+        color: Color,
+        connectivity: Connectivity,
+        tolerance: u8,
+    ) {
+        let p = pos.into();
+        if p.x < 0 || p.y < 0 { return; }
+
+        self.push(DrawCommand::FloodFill { pos: p, color, connectivity, tolerance });
+    }
+    /// Push already built draw commands, eg. the ones of a recorded frame
+    pub fn replay(&mut self, commands: &[DrawCommand]) {
+        for cmd in commands {
+            self.push(cmd.clone());
+        }
+    }
+    /// Draw all following [Graphics::text] calls with a [BitmapFont], this sticks until it's changed again
+    pub fn set_font(&mut self, font: BitmapFont) {
+        self.font = Some(font);
+    }
+    /// Go back to the built in 8x8 font
+    pub fn reset_font(&mut self) {
+        self.font = None;
+    }
+    /// The [BitmapFont] [Graphics::text] draws with, `None` if it's the built in 8x8 font
+    pub fn font(&self) -> Option<&BitmapFont> {
+        self.font.as_ref()
+    }
+    /// Draw all following bitmap text with `style`, this sticks until it's changed again
+    pub fn set_text_style(&mut self, style: TextStyle) {
+        self.text_style = style;
+    }
+    /// The [TextStyle] bitmap text is currently drawn with
+    pub fn text_style(&self) -> TextStyle {
+        self.text_style
+    }
+    /// Draw and measure text with a TrueType/OpenType [Font](crate::Font) at `size` pixels inside `f`. <br>
+    /// Everything that uses the current font picks it up, eg. [Graphics::text_aligned], [Graphics::text_box] or [Graphics::measure_text].
+    #[cfg(feature = "ttf")]
+    pub fn with_font(&mut self, font: &crate::Font, size: f32, f: impl FnOnce(&mut Self)) {
+        let previous = self.ttf_font.replace((font.clone(), size));
+        f(self);
+        self.ttf_font = previous;
+    }
+    fn text_font(&self) -> TextFont<'_> {
+        #[cfg(feature = "ttf")]
+        if let Some((font, size)) = &self.ttf_font {
+            return TextFont::TrueType(font, *size);
+        }
+        match &self.font {
+            Some(font) => TextFont::Bitmap(font),
+            None => TextFont::Builtin,
+        }
+    }
+    /// Draw with `style` inside `f`, then go back to the previous style
+    pub fn with_text_style(&mut self, style: TextStyle, f: impl FnOnce(&mut Self)) {
+        let previous = self.text_style;
+        self.text_style = style;
+        f(self);
+        self.text_style = previous;
+    }
+    /// Size of text in pixels when drawn with the current font and [TextStyle]. <br>
+    /// Multi-line text is as wide as its widest line, tabs are measured up to their tab stop.
+    pub fn measure_text(&self, text: &str) -> Vec2 {
+        let width = lines(text).map(|line| self.line_width(line)).max().unwrap_or(0);
+        Vec2::new(width, self.block_height(lines(text).count()))
+    }
+    /// Break `text` into lines that are at most `max_width` pixels wide with the current font. <br>
+    /// Lines are broken between words, words wider than `max_width` are split.
+    pub fn wrap_text(&self, text: &str, max_width: i32) -> Vec<String> {
+        text::wrap(text, max_width, |line| self.measure_text(line).x)
+    }
+    /// Height of a line of the current font
+    fn line_height(&self) -> i32 {
+        let height = match self.text_font() {
+            TextFont::Builtin => 8,
+            TextFont::Bitmap(font) => font.line_height(),
+            #[cfg(feature = "ttf")]
+            TextFont::TrueType(font, size) => font.line_height(size).round() as i32,
+        };
+        height * self.text_style.scale_y as i32
+    }
+    /// Distance between the tops of two lines, including the line spacing of the [TextStyle]
+    fn line_advance(&self) -> i32 {
+        self.line_height() + self.text_style.line_spacing
+    }
+    /// Height of `lines` lines drawn below each other
+    fn block_height(&self, lines: usize) -> i32 {
+        match lines {
+            0 => 0,
+            n => self.line_advance() * (n as i32 - 1) + self.line_height(),
+        }
+    }
+    /// Width of text without tabs or newlines
+    fn run_width(&self, run: &str) -> i32 {
+        let width = match self.text_font() {
+            TextFont::Builtin => run.chars().count() as i32 * 8,
+            TextFont::Bitmap(font) => font.text_width(run),
+            #[cfg(feature = "ttf")]
+            TextFont::TrueType(font, size) => font.text_width(size, run).round() as i32,
+        };
+        width * self.text_style.scale_x as i32
+    }
+    /// Where a tab at `x` pixels into a line jumps to
+    fn next_tab_stop(&self, x: i32) -> i32 {
+        let stop = (self.run_width(" ") * self.text_style.tab_width.max(1) as i32).max(1);
+        (x / stop + 1) * stop
+    }
+    /// Width of a single line, tabs included
+    fn line_width(&self, line: &str) -> i32 {
+        self.line_end(0, line)
+    }
+    /// Where a line starting `x` pixels in ends, tab stops still count from 0
+    fn line_end(&self, mut x: i32, line: &str) -> i32 {
+        for (i, run) in line.split('\t').enumerate() {
+            if i > 0 {
+                x = self.next_tab_stop(x);
+            }
+            x += self.run_width(run);
+        }
+        x
+    }
+    /// Rows from the top of a line to the baseline of the current font
+    fn text_ascent(&self) -> i32 {
+        let ascent = match self.text_font() {
+            // The built in glyphs sit on row 7, only descenders go below it
+            TextFont::Builtin => 7,
+            TextFont::Bitmap(font) => font.ascent(),
+            #[cfg(feature = "ttf")]
+            TextFont::TrueType(font, size) => font.ascent(size).round() as i32,
+        };
+        ascent * self.text_style.scale_y as i32
+    }
+    /// Draw text aligned to `anchor`, eg. [TextAlign::CENTER] centers the text on it. <br>
+    /// Every line of multi-line text is aligned horizontally on its own, [VAlign::Baseline] uses the first line.
+    pub fn text_aligned(&mut self, anchor: impl Into<Vec2>, text: impl Into<String>, align: TextAlign, color: Color) {
+        let text = text.into();
+        let anchor = anchor.into();
+        let height = self.measure_text(&text).y;
+        let top = match align.vertical {
+            VAlign::Top => anchor.y,
+            VAlign::Middle => anchor.y - height / 2,
+            VAlign::Baseline => anchor.y - self.text_ascent(),
+            VAlign::Bottom => anchor.y - height,
+        };
+        let advance = self.line_advance();
+        for (i, line) in lines(&text).enumerate() {
+            let width = self.line_width(line);
+            let x = match align.horizontal {
+                HAlign::Left => anchor.x,
+                HAlign::Center => anchor.x - width / 2,
+                HAlign::Right => anchor.x - width,
+            };
+            self.text_line(Vec2::new(x, top + i as i32 * advance), 0, line, color);
+        }
+    }
+    /// Draw a line of text aligned inside `rect`, eg. a centered label on a button
+    pub fn text_in_rect(&mut self, rect: Rect, text: impl Into<String>, align: TextAlign, color: Color) {
+        let anchor = Self::rect_anchor(rect, align, self.line_height() - self.text_ascent());
+        self.text_aligned(anchor, text, align, color);
+    }
+    /// Draw `text` word wrapped to the width of `rect`. <br>
+    /// Every line is aligned horizontally on its own, the whole block is aligned vertically inside `rect`.
+    /// Lines that don't fit in the height of `rect` are still drawn, see [Graphics::wrap_text] to measure them first.
+    pub fn text_box(&mut self, rect: Rect, text: &str, align: TextAlign, color: Color) {
+        let lines = self.wrap_text(text, rect.size.x);
+        let advance = self.line_advance();
+        let block = self.block_height(lines.len());
+        let top = match align.vertical {
+            VAlign::Top => rect.pos.y,
+            VAlign::Middle => rect.pos.y + (rect.size.y - block) / 2,
+            VAlign::Baseline | VAlign::Bottom => rect.pos.y + rect.size.y - block,
+        };
+
+        let line_align = TextAlign::new(align.horizontal, VAlign::Top);
+        for (i, line) in lines.into_iter().enumerate() {
+            let anchor = Self::rect_anchor(rect, line_align, 0);
+            self.text_aligned((anchor.x, top + i as i32 * advance), line, line_align, color);
+        }
+    }
+    /// Point inside `rect` that text with `align` gets anchored to. <br>
+    /// `descent` keeps baseline aligned text from hanging out of the bottom.
+    fn rect_anchor(rect: Rect, align: TextAlign, descent: i32) -> Vec2 {
+        let x = match align.horizontal {
+            HAlign::Left => rect.pos.x,
+            HAlign::Center => rect.pos.x + rect.size.x / 2,
+            HAlign::Right => rect.pos.x + rect.size.x,
+        };
+        let y = match align.vertical {
+            VAlign::Top => rect.pos.y,
+            VAlign::Middle => rect.pos.y + rect.size.y / 2,
+            VAlign::Baseline => rect.pos.y + rect.size.y - descent,
+            VAlign::Bottom => rect.pos.y + rect.size.y,
+        };
+        Vec2::new(x, y)
+    }
+    /// Draw text at `pos` with the specified color. <br>
+    /// Uses the internal 8x8 bitmap font unless another one was set with [Graphics::set_font], scaled by the current [TextStyle].
+    /// `\n` or `\r\n` starts a new line below `pos` and `\t` jumps to the next tab stop of the [TextStyle].
     pub fn text<T: Into<String>>(&mut self, pos: impl Into<Vec2>, text: T, color: Color) {
-        self.commands.push(DrawCommand::Text {
-            pos: pos.into(),
-            text: text.into(),
+        let text = text.into();
+        let pos = pos.into();
+        let advance = self.line_advance();
+        for (i, line) in lines(&text).enumerate() {
+            self.text_line(Vec2::new(pos.x, pos.y + i as i32 * advance), 0, line, color);
+        }
+    }
+    /// Draw text made of differently colored spans, eg. `&[("HP: ", Color::WHITE), ("12", Color::RED)]`. <br>
+    /// Spans continue where the previous one stopped, newlines and tabs work like in [Graphics::text].
+    pub fn text_spans<S: AsRef<str>>(&mut self, pos: impl Into<Vec2>, spans: &[(S, Color)]) {
+        let pos = pos.into();
+        let advance = self.line_advance();
+        let (mut x, mut row) = (0, 0);
+        for (span, color) in spans {
+            for (i, line) in lines(span.as_ref()).enumerate() {
+                if i > 0 {
+                    (x, row) = (0, row + 1);
+                }
+                x = self.text_line(Vec2::new(pos.x, pos.y + row * advance), x, line, *color);
+            }
+        }
+    }
+    /// Draw text with inline color tags, eg. `"HP: {red}12{/}"`. <br>
+    /// `{red}` or `{#ff8800}` switches to that color, `{/}` goes back to the one before and `{{` draws a `{`.
+    /// Text outside of tags is drawn with `color`, see [Graphics::text_spans] for the details.
+    pub fn text_markup(&mut self, pos: impl Into<Vec2>, text: &str, color: Color) {
+        let spans = text::markup(text, color);
+        self.text_spans(pos, &spans);
+    }
+    /// Draw a single line starting `x` pixels after `pos`, every stretch between tabs becomes its own command. <br>
+    /// Returns where the line ended.
+    fn text_line(&mut self, pos: Vec2, mut x: i32, line: &str, color: Color) -> i32 {
+        if let Some(background) = self.text_style.background {
+            let width = self.line_end(x, line) - x;
+            self.rect(Vec2::new(pos.x + x, pos.y), Vec2::new(width, self.line_height()), background);
+        }
+        for (i, run) in line.split('\t').enumerate() {
+            if i > 0 {
+                x = self.next_tab_stop(x);
+            }
+            if !run.is_empty() {
+                self.text_run(Vec2::new(pos.x + x, pos.y), run, color);
+            }
+            x += self.run_width(run);
+        }
+        x
+    }
+    /// Draw text without tabs or newlines, the shadow and outline of the [TextStyle] go below it
+    fn text_run(&mut self, pos: Vec2, run: &str, color: Color) {
+        let TextStyle { scale_x, scale_y, shadow, outline, .. } = self.text_style;
+        let mut passes = Vec::new();
+        if let Some((offset, shadow)) = shadow {
+            passes.push((pos + offset, shadow));
+        }
+        if let Some(outline) = outline {
+            for (dx, dy) in [(-1, -1), (0, -1), (1, -1), (-1, 0), (1, 0), (-1, 1), (0, 1), (1, 1)] {
+                passes.push((pos + Vec2::new(dx, dy), outline));
+            }
+        }
+        passes.push((pos, color));
+
+        let mask = match self.text_font() {
+            TextFont::Builtin => None,
+            TextFont::Bitmap(font) => Some(font.rasterize(run)),
+            #[cfg(feature = "ttf")]
+            TextFont::TrueType(font, size) => Some(font.rasterize(size, run)),
+        };
+        if let Some(mask) = mask {
+            let mask = mask.scaled(scale_x, scale_y);
+            for (pos, color) in passes {
+                self.mask(pos, mask.clone(), color);
+            }
+            return;
+        }
+        for (pos, color) in passes {
+            self.push(DrawCommand::Text {
+                pos,
+                text: run.to_string(),
+                color,
+                scale: (scale_x, scale_y),
+            });
+        }
+    }
+    /// Draw a [CharGrid] with its top left corner at `pos`. <br>
+    /// Always uses the built in 8x8 font, cells are 8x8 pixels times the scale of the current [TextStyle].
+    pub fn char_grid(&mut self, pos: impl Into<Vec2>, grid: &CharGrid) {
+        let pos = pos.into();
+        let scale = (self.text_style.scale_x, self.text_style.scale_y);
+        let cell = Vec2::new(8 * scale.0 as i32, 8 * scale.1 as i32);
+
+        for (row, cells) in grid.cells().chunks(grid.cols().max(1)).enumerate() {
+            let y = pos.y + row as i32 * cell.y;
+            // Neighbouring cells with the same colors are drawn as one command
+            let mut col = 0;
+            for run in cells.chunk_by(|a, b| a.bg == b.bg) {
+                self.rect((pos.x + col * cell.x, y), (run.len() as i32 * cell.x, cell.y), run[0].bg);
+                col += run.len() as i32;
+            }
+            let mut col = 0;
+            for run in cells.chunk_by(|a, b| a.fg == b.fg) {
+                if run.iter().any(|c| c.glyph != ' ') {
+                    self.push(DrawCommand::Text {
+                        pos: Vec2::new(pos.x + col * cell.x, y),
+                        text: run.iter().map(|c| c.glyph).collect(),
+                        color: run[0].fg,
+                        scale,
+                    });
+                }
+                col += run.len() as i32;
+            }
+        }
+
+        // A cursor waiting to wrap past the last column stays drawn on it
+        let (col, row) = grid.cursor();
+        let col = col.min(grid.cols().saturating_sub(1));
+        if let Some(c) = grid.cell(col, row)
+            && grid.cursor_visible()
+        {
+            let at = Vec2::new(pos.x + col as i32 * cell.x, pos.y + row as i32 * cell.y);
+            self.rect(at, cell, c.fg);
+            self.push(DrawCommand::Text { pos: at, text: c.glyph.to_string(), color: c.bg, scale });
+        }
+    }
+    /// Draw text with the built in vector font, `pos` is the top left of the first line. <br>
+    /// The glyphs are drawn as lines so they stay sharp at any size and rotation, see [StrokeStyle].
+    pub fn stroke_text(&mut self, pos: impl Into<Vec2>, text: impl AsRef<str>, style: StrokeStyle, color: Color) {
+        let pos = pos.into();
+        let to_pixel = |(x, y): (f32, f32)| Vec2::new(pos.x + x.round() as i32, pos.y + y.round() as i32);
+        for polyline in style.layout(text.as_ref()) {
+            let points: Vec<Vec2> = polyline.into_iter().map(to_pixel).collect();
+            for segment in points.windows(2) {
+                self.thick_line(segment[0], segment[1], style.thickness, color);
+            }
+        }
+    }
+    /// Size of text drawn with [Graphics::stroke_text] in pixels, ignoring the rotation
+    pub fn measure_stroke_text(&self, text: &str, style: StrokeStyle) -> Vec2 {
+        let width = lines(text).map(|line| style.line_width(line)).fold(0.0, f32::max);
+        let height = style.block_height(lines(text).count());
+        Vec2::new(width.ceil() as i32, height.ceil() as i32)
+    }
+    /// A line `thickness` pixels wide, made of parallel lines with square joints
+    fn thick_line(&mut self, start: Vec2, end: Vec2, thickness: u32, color: Color) {
+        if thickness <= 1 {
+            self.line(start, end, color);
+            return;
+        }
+        let t = thickness as i32;
+        let (dx, dy) = (end.x - start.x, end.y - start.y);
+        // Stack copies across the minor axis, diagonal lines need more of them to look as thick
+        let major = dx.abs().max(dy.abs()).max(1) as f32;
+        let copies = ((t as f32 * (dx as f32).hypot(dy as f32) / major).round() as i32).max(t);
+        let shift = if dx.abs() >= dy.abs() { Vec2::new(0, 1) } else { Vec2::new(1, 0) };
+        for i in 0..copies {
+            let offset = Vec2::new(shift.x * (i - copies / 2), shift.y * (i - copies / 2));
+            self.line(start + offset, end + offset, color);
+        }
+        for joint in [start, end] {
+            self.rect(Vec2::new(joint.x - t / 2, joint.y - t / 2), Vec2::new(t, t), color);
+        }
+    }
+    /// Draw text at `pos` with a TrueType/OpenType [Font](crate::Font) at `size` pixels. <br>
+    /// Works like [Graphics::text] with the current [TextStyle], the edges are drawn according to the font's [TextMode](crate::TextMode).
+    /// See [Graphics::with_font] for aligned or wrapped text.
+    #[cfg(feature = "ttf")]
+    pub fn text_with(&mut self, font: &crate::Font, size: f32, pos: impl Into<Vec2>, text: impl AsRef<str>, color: Color) {
+        let pos = pos.into();
+        self.with_font(font, size, |gfx| gfx.text(pos, text.as_ref(), color));
+    }
+    fn mask(&mut self, pos: Vec2, mask: TextMask, color: Color) {
+        if mask.alpha.is_empty() { return; }
+
+        self.push(DrawCommand::Mask {
+            pos: pos + mask.offset,
+            size: mask.size,
+            alpha: mask.alpha,
             color,
         });
-    }   
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn graphics() -> Graphics {
+        Graphics::new(LogicalSize::new(64, 48), PhysicalSize::new(64, 48), ScalingMode::default(), 1)
+    }
+
+    fn text_positions(gfx: &Graphics) -> Vec<Vec2> {
+        gfx.commands()
+            .iter()
+            .filter_map(|cmd| match cmd {
+                DrawCommand::Text { pos, .. } => Some(*pos),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn effects_draw_below_the_text() {
+        let mut gfx = graphics();
+        let style = TextStyle::default()
+            .with_background(Color::BLUE)
+            .with_shadow(Vec2::new(1, 2), Color::RED)
+            .with_outline(Color::BLACK);
+        gfx.set_text_style(style);
+        gfx.text((10, 20), "Hi", Color::WHITE);
+
+        let commands = gfx.commands();
+        assert_eq!(commands.len(), 1 + 1 + 8 + 1);
+        assert!(matches!(
+            commands[0],
+            DrawCommand::Rect { pos: Vec2 { x: 10, y: 20 }, size: Vec2 { x: 16, y: 8 }, color: Color::BLUE }
+        ));
+        let colors: Vec<Color> = commands[1..]
+            .iter()
+            .map(|cmd| match cmd {
+                DrawCommand::Text { color, .. } => *color,
+                other => panic!("expected text, got {:?}", other),
+            })
+            .collect();
+        assert_eq!(colors[0], Color::RED);
+        assert!(colors[1..9].iter().all(|&c| c == Color::BLACK));
+        assert_eq!(colors[9], Color::WHITE);
+        assert_eq!(text_positions(&gfx)[0], Vec2::new(11, 22));
+    }
+
+    #[test]
+    fn effect_passes_keep_their_offsets_at_the_edge() {
+        let mut gfx = graphics();
+        let style = TextStyle::default().with_outline(Color::BLACK).with_shadow(Vec2::new(-2, -3), Color::RED);
+        gfx.set_text_style(style);
+        gfx.text((0, 0), "A", Color::WHITE);
+
+        let mut positions = text_positions(&gfx);
+        assert_eq!(positions.remove(0), Vec2::new(-2, -3));
+        assert_eq!(positions.pop(), Some(Vec2::new(0, 0)));
+        // Eight different outline offsets, none of them squashed onto the main pass
+        positions.sort_by_key(|pos| (pos.x, pos.y));
+        positions.dedup();
+        assert_eq!(positions.len(), 8);
+        assert!(!positions.contains(&Vec2::new(0, 0)));
+    }
+
+    #[test]
+    fn wide_aligned_text_keeps_negative_positions() {
+        let mut gfx = graphics();
+        let line = "twenty characters...";
+        gfx.text_aligned((32, 10), line, TextAlign::new(HAlign::Center, VAlign::Top), Color::WHITE);
+        gfx.text_aligned((60, 30), line, TextAlign::new(HAlign::Right, VAlign::Bottom), Color::WHITE);
+        assert_eq!(text_positions(&gfx), vec![Vec2::new(32 - 80, 10), Vec2::new(60 - 160, 30 - 8)]);
+    }
+
+    #[test]
+    fn only_changed_frames_are_dirty() {
+        let mut gfx = graphics();
+        let frame = |gfx: &mut Graphics, x: i32| {
+            gfx.begin_frame();
+            gfx.clear(Color::BLACK);
+            gfx.rect((x, 4), (8, 8), Color::RED);
+            gfx.end_frame();
+            (gfx.frame_changed(), gfx.generation())
+        };
+
+        assert_eq!(frame(&mut gfx, 4), (true, 1));
+        assert_eq!(frame(&mut gfx, 4), (false, 1));
+        assert_eq!(frame(&mut gfx, 5), (true, 2));
+        assert_eq!(frame(&mut gfx, 5), (false, 2));
+
+        // Only for the one frame it was asked for
+        gfx.request_redraw();
+        assert_eq!(frame(&mut gfx, 5), (true, 3));
+        assert_eq!(frame(&mut gfx, 5), (false, 3));
+    }
+
+    #[test]
+    fn layers_sort_back_to_front_and_keep_their_order() {
+        let mut gfx = graphics();
+        gfx.begin_frame();
+        // Pixel x marks the submission order, y the layer (moved onto the canvas)
+        for (x, layer) in [(0, 2), (1, -1), (2, 0), (3, 2), (4, -1), (5, 0), (6, 2)] {
+            gfx.layer(layer);
+            gfx.pixel((x, layer + 10), Color::WHITE);
+        }
+        gfx.with_layer(1, |gfx| gfx.pixel((7, 11), Color::WHITE));
+        assert_eq!(gfx.current_layer(), 2);
+        gfx.end_frame();
+
+        let drawn: Vec<(i32, i32)> = gfx
+            .commands()
+            .iter()
+            .map(|cmd| match cmd {
+                DrawCommand::Pixel { pos, .. } => (pos.y - 10, pos.x),
+                other => panic!("expected a pixel, got {:?}", other),
+            })
+            .collect();
+        assert_eq!(drawn, [(-1, 1), (-1, 4), (0, 2), (0, 5), (1, 7), (2, 0), (2, 3), (2, 6)]);
+
+        // The next frame starts over on layer 0
+        gfx.begin_frame();
+        assert_eq!(gfx.current_layer(), 0);
+    }
+
+    #[test]
+    fn line_spacing_and_tab_stops() {
+        let mut gfx = graphics();
+        gfx.set_text_style(TextStyle { line_spacing: 3, tab_width: 2, ..TextStyle::default() });
+        gfx.text((5, 6), "ab\tc\n\td\n\ne", Color::WHITE);
+        // Tab stops are every 16 pixels from the start of the line, lines 11 pixels apart
+        assert_eq!(text_positions(&gfx), [Vec2::new(5, 6), Vec2::new(5 + 32, 6), Vec2::new(5 + 16, 17), Vec2::new(5, 39)]);
+        assert_eq!(gfx.measure_text("ab\tc\n\td\n\ne"), Vec2::new(40, 3 * 11 + 8));
+
+        let mut gfx = graphics();
+        gfx.set_text_style(TextStyle { line_spacing: -2, tab_width: 0, ..TextStyle::default() });
+        gfx.text((0, 20), "a\tb\nc", Color::WHITE);
+        // A tab width of 0 still moves on to the next character cell
+        assert_eq!(text_positions(&gfx), [Vec2::new(0, 20), Vec2::new(16, 20), Vec2::new(0, 26)]);
+    }
+
+    #[test]
+    fn windows_line_endings() {
+        let mut gfx = graphics();
+        gfx.text((2, 3), "one\r\ntwo\r\n", Color::WHITE);
+        gfx.text_markup((2, 30), "{red}a\r\n{/}b", Color::WHITE);
+        let drawn: Vec<(Vec2, &str)> = gfx
+            .commands()
+            .iter()
+            .filter_map(|cmd| match cmd {
+                DrawCommand::Text { pos, text, .. } => Some((*pos, text.as_str())),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(drawn, [(Vec2::new(2, 3), "one"), (Vec2::new(2, 11), "two"), (Vec2::new(2, 30), "a"), (Vec2::new(2, 38), "b")]);
+
+        assert_eq!(gfx.measure_text("one\r\ntwo\r\n"), gfx.measure_text("one\ntwo\n"));
+        assert_eq!(gfx.wrap_text("one two\r\nthree", 60), ["one two", "three"]);
+        // A lone `\r` isn't a line break
+        assert_eq!(gfx.measure_text("a\rb"), Vec2::new(24, 8));
+    }
+
+    #[test]
+    fn measuring_with_a_non_uniform_scale() {
+        let mut gfx = graphics();
+        gfx.set_text_style(TextStyle::default().with_scale(2, 3));
+        assert_eq!(gfx.measure_text("ab"), Vec2::new(32, 24));
+        assert_eq!(gfx.measure_text("ab\ncde"), Vec2::new(48, 48));
+        // Tab stops are 4 scaled spaces apart
+        assert_eq!(gfx.measure_text("a\tb"), Vec2::new(64 + 16, 24));
+        assert_eq!(gfx.wrap_text("ab cd", 40), ["ab", "cd"]);
+
+        gfx.text_aligned((60, 40), "ab", TextAlign::new(HAlign::Right, VAlign::Bottom), Color::WHITE);
+        gfx.text_aligned((60, 40), "ab", TextAlign::new(HAlign::Left, VAlign::Baseline), Color::WHITE);
+        assert_eq!(text_positions(&gfx), [Vec2::new(28, 16), Vec2::new(60, 40 - 21)]);
+        assert!(gfx.commands().iter().all(|cmd| matches!(cmd, DrawCommand::Text { scale: (2, 3), .. })));
+    }
+
+    #[cfg(feature = "ttf")]
+    #[test]
+    fn true_type_text_works_like_bitmap_text() {
+        // 10px `I` glyphs are 3x8 bars one pixel into a 5 pixel advance, lines are 10 pixels high
+        let font = crate::Font::from_bytes(include_bytes!("../tests/fonts/rects.ttf").as_slice()).unwrap();
+        let masks = |gfx: &Graphics| -> Vec<(Vec2, Vec2)> {
+            gfx.commands()
+                .iter()
+                .map(|cmd| match cmd {
+                    DrawCommand::Mask { pos, size, .. } => (*pos, *size),
+                    other => panic!("expected a mask, got {:?}", other),
+                })
+                .collect()
+        };
+
+        let mut gfx = graphics();
+        gfx.set_text_style(TextStyle { line_spacing: 2, ..TextStyle::default().with_scale(2, 1) });
+        gfx.text_with(&font, 10.0, (4, 4), "I\n\tI", Color::WHITE);
+        assert_eq!(
+            masks(&gfx),
+            [(Vec2::new(6, 4), Vec2::new(6, 8)), (Vec2::new(4 + 40 + 2, 16), Vec2::new(6, 8))]
+        );
+
+        let mut gfx = graphics();
+        gfx.with_font(&font, 10.0, |gfx| {
+            assert_eq!(gfx.measure_text("II\nI\tI"), Vec2::new(25, 20));
+            assert_eq!(gfx.wrap_text("II II", 12), ["II", "II"]);
+            gfx.text_aligned((32, 20), "II", TextAlign::new(HAlign::Center, VAlign::Baseline), Color::WHITE);
+        });
+        assert_eq!(masks(&gfx), [(Vec2::new(28, 12), Vec2::new(8, 8))]);
+        // Back to the built in font afterwards
+        assert_eq!(gfx.measure_text("II"), Vec2::new(16, 8));
+    }
 }
@@ -1,56 +1,246 @@
 use std::collections::HashSet;
 
-use winit::event::{MouseButton, KeyEvent};
+use winit::event::{DeviceEvent, ElementState, Ime, MouseButton, MouseScrollDelta, KeyEvent};
 use winit::keyboard::{KeyCode, PhysicalKey};
 
 use winit_input_helper::WinitInputHelper;
 
 use crate::Graphics;
+use crate::actions::{ActionMap, Binding};
+use crate::gamepad::{GamepadAxis, GamepadButton, GamepadId, GamepadProvider, Gamepads};
+#[cfg(feature = "record")]
+use crate::record::InputSnapshot;
+
+/// How far the mouse wheel or trackpad scrolled during a frame. <br>
+/// Wheels usually report whole lines and trackpads exact pixels, a frame can contain both.
+/// Positive values scroll right and up, ie. the wheel was turned away from the user.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "record", derive(serde::Serialize, serde::Deserialize))]
+pub struct ScrollDelta {
+    /// Lines scrolled horizontally and vertically
+    pub lines: (f32, f32),
+    /// Physical pixels scrolled horizontally and vertically
+    pub pixels: (f32, f32),
+}
+
+/// How the cursor is held inside the window, see [Input::set_cursor_grab]. <br>
+/// Most platforms only support one of the two grabs, when the requested one fails the other is used instead.
+/// [Input::cursor_grab] still returns the requested one.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum CursorGrab {
+    /// The cursor moves freely
+    #[default]
+    None,
+    /// The cursor can't leave the window. <br>
+    /// Falls back to [CursorGrab::Locked] where confining isn't supported, eg. on macOS and the web
+    Confined,
+    /// The cursor stays where it is, read the movement with [Input::mouse_delta]. <br>
+    /// Falls back to [CursorGrab::Confined] where locking isn't supported, eg. on Windows and X11
+    Locked,
+}
 
 /// This is the Main Window Input Interface.
 pub struct Input {
     pub(crate) helper: WinitInputHelper,
     mouse_logical: Option<(f32,f32)>,
+    mouse_window: Option<(f32,f32)>,
+    mouse_delta: (f32, f32),
+    cursor_visible: bool,
+    cursor_grab: CursorGrab,
+    /// Cursor changes that still need to be applied to the window
+    pub(crate) cursor_changed: bool,
     
     // Key Tracking
     keys_pressed: HashSet<PhysicalKey>,
     keys_released: HashSet<PhysicalKey>,
     keys_held: HashSet<PhysicalKey>,
+
+    // Mouse button tracking, the helper only answers for buttons it's asked about
+    mouse_pressed: HashSet<MouseButton>,
+    mouse_released: HashSet<MouseButton>,
+    mouse_held: HashSet<MouseButton>,
+
+    scroll: ScrollDelta,
+
+    // Typing
+    text: String,
+    preedit: Option<(String, Option<(usize, usize)>)>,
+
+    pub(crate) gamepads: Gamepads,
+
+    actions: ActionMap,
+
+    /// The state comes from a recording, see [Input::is_replaying]
+    #[cfg(feature = "record")]
+    replaying: bool,
 }
 
 impl Input {
-    pub(crate) fn new() -> Self {
+    pub(crate) fn new(gamepad_provider: Option<Box<dyn GamepadProvider>>, actions: ActionMap) -> Self {
         Self {
             helper: WinitInputHelper::new(),
             mouse_logical: None,
+            mouse_window: None,
+            mouse_delta: (0.0, 0.0),
+            cursor_visible: true,
+            cursor_grab: CursorGrab::None,
+            cursor_changed: false,
             keys_pressed: HashSet::new(),
             keys_released: HashSet::new(),
             keys_held: HashSet::new(),
+            mouse_pressed: HashSet::new(),
+            mouse_released: HashSet::new(),
+            mouse_held: HashSet::new(),
+            scroll: ScrollDelta::default(),
+            text: String::new(),
+            preedit: None,
+            gamepads: Gamepads::new(gamepad_provider),
+            actions,
+            #[cfg(feature = "record")]
+            replaying: false,
         }
     }
 
     /// Process a keyboard event directly
     pub(crate) fn process_key_event(&mut self, event: &KeyEvent) {
-        let physical_key = event.physical_key;
-        
-        match event.state {
-            winit::event::ElementState::Pressed => {
+        self.process_key(event.physical_key, event.state, event.text.as_deref());
+    }
+
+    /// Split out of [Input::process_key_event] because a `KeyEvent` can only be made by winit
+    pub(crate) fn process_key(&mut self, physical_key: PhysicalKey, state: ElementState, text: Option<&str>) {
+        match state {
+            ElementState::Pressed => {
                 if !self.keys_held.contains(&physical_key) {
                     self.keys_pressed.insert(physical_key);
                 }
                 self.keys_held.insert(physical_key);
+
+                // Enter, Backspace & co. come through as control characters, they're checked with key_pressed instead
+                if let Some(text) = text {
+                    self.text.extend(text.chars().filter(|c| !c.is_control()));
+                }
             }
-            winit::event::ElementState::Released => {
+            ElementState::Released => {
                 self.keys_held.remove(&physical_key);
                 self.keys_released.insert(physical_key);
             }
         }
     }
 
+    /// Process a mouse button event directly
+    pub(crate) fn process_mouse_button(&mut self, button: MouseButton, state: ElementState) {
+        match state {
+            ElementState::Pressed => {
+                if self.mouse_held.insert(button) {
+                    self.mouse_pressed.insert(button);
+                }
+            }
+            ElementState::Released => {
+                if self.mouse_held.remove(&button) {
+                    self.mouse_released.insert(button);
+                }
+            }
+        }
+    }
+
+    /// Add up a mouse wheel event, the helper only keeps lines so pixel deltas are tracked here
+    pub(crate) fn process_scroll_event(&mut self, delta: &MouseScrollDelta) {
+        match *delta {
+            MouseScrollDelta::LineDelta(x, y) => {
+                self.scroll.lines.0 += x;
+                self.scroll.lines.1 += y;
+            }
+            MouseScrollDelta::PixelDelta(pos) => {
+                self.scroll.pixels.0 += pos.x as f32;
+                self.scroll.pixels.1 += pos.y as f32;
+            }
+        }
+    }
+
+    /// Raw mouse movement, it keeps coming when the cursor is locked or hits the edge of the screen
+    pub(crate) fn process_device_event(&mut self, event: &DeviceEvent) {
+        if let DeviceEvent::MouseMotion { delta } = event {
+            self.mouse_delta.0 += delta.0 as f32;
+            self.mouse_delta.1 += delta.1 as f32;
+        }
+    }
+
+    /// Text composed with the input method editor
+    pub(crate) fn process_ime_event(&mut self, ime: &Ime) {
+        match ime {
+            Ime::Commit(text) => {
+                self.text.push_str(text);
+                self.preedit = None;
+            }
+            Ime::Preedit(text, _) if text.is_empty() => self.preedit = None,
+            Ime::Preedit(text, cursor) => self.preedit = Some((text.clone(), *cursor)),
+            Ime::Enabled => {}
+            Ime::Disabled => self.preedit = None,
+        }
+    }
+
     /// Clear transient input state (pressed/released) for the next frame
     pub(crate) fn reset_transient_state(&mut self) {
         self.keys_pressed.clear();
         self.keys_released.clear();
+        self.mouse_pressed.clear();
+        self.mouse_released.clear();
+        self.scroll = ScrollDelta::default();
+        self.mouse_delta = (0.0, 0.0);
+        self.text.clear();
+        self.gamepads.reset_transient_state();
+    }
+
+    /// Capture the current input state
+    #[cfg(feature = "record")]
+    pub fn snapshot(&self) -> InputSnapshot {
+        InputSnapshot {
+            keys_held: sorted(&self.keys_held),
+            keys_pressed: sorted(&self.keys_pressed),
+            keys_released: sorted(&self.keys_released),
+            mouse_pos: self.mouse_logical,
+            mouse_window_pos: self.mouse_window,
+            mouse_held: sorted(&self.mouse_held),
+            mouse_pressed: sorted(&self.mouse_pressed),
+            mouse_released: sorted(&self.mouse_released),
+            scroll: self.scroll,
+            mouse_delta: self.mouse_delta,
+            text: self.text.clone(),
+        }
+    }
+
+    /// Replace the state of this frame with a recorded one, typed keys and mouse moves in between are dropped
+    #[cfg(feature = "record")]
+    pub(crate) fn replay(&mut self, snapshot: InputSnapshot) {
+        self.keys_held = snapshot.keys_held.iter().copied().collect();
+        self.keys_pressed = snapshot.keys_pressed.iter().copied().collect();
+        self.keys_released = snapshot.keys_released.iter().copied().collect();
+        self.mouse_logical = snapshot.mouse_pos;
+        self.mouse_window = snapshot.mouse_window_pos;
+        self.mouse_held = snapshot.mouse_held.into_iter().collect();
+        self.mouse_pressed = snapshot.mouse_pressed.into_iter().collect();
+        self.mouse_released = snapshot.mouse_released.into_iter().collect();
+        self.scroll = snapshot.scroll;
+        self.mouse_delta = snapshot.mouse_delta;
+        self.text = snapshot.text;
+        self.preedit = None;
+        self.replaying = true;
+    }
+
+    /// Hand the input back to the keyboard and mouse once the playback is over
+    #[cfg(feature = "record")]
+    pub(crate) fn stop_replay(&mut self) {
+        if self.replaying {
+            self.replaying = false;
+            self.keys_held.clear();
+            self.mouse_held.clear();
+        }
+    }
+
+    /// Returns true while the input comes from a recording. See `Config::builder().play_input_from(..)`
+    #[cfg(feature = "record")]
+    pub fn is_replaying(&self) -> bool {
+        self.replaying
     }
 
     // ? Keyboard
@@ -70,35 +260,178 @@ impl Input {
         self.keys_released.iter().any(|&k| k == PhysicalKey::Code(key))
     }
 
+    /// Returns the characters typed this frame, including text committed by the input method editor. <br>
+    /// Control keys like Enter or Backspace aren't part of it, check those with [Input::key_pressed].
+    pub fn text_entered(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns the text the input method editor is still composing and the byte range of its cursor in it. <br>
+    /// Show it where the text will go, it turns into [Input::text_entered] once it's committed.
+    /// Needs the IME to be turned on, see [Config::ime_allowed](crate::Config::ime_allowed).
+    pub fn ime_preedit(&self) -> Option<(&str, Option<(usize, usize)>)> {
+        self.preedit.as_ref().map(|(text, cursor)| (text.as_str(), *cursor))
+    }
+
     // ? Mouse
     pub(crate) fn update_mouse_mapping(&mut self, gfx: &Graphics) {
-        self.mouse_logical = self.helper.cursor().map(|(mx, my)| {
-            let (lw, lh) = gfx.logical_size();
-            let (ww, wh) = gfx.window_size();
-
-            let x = mx * lw as f32 / ww as f32;
-            let y = my * lh as f32 / wh as f32;
-            return (x , y);
-        });
+        self.mouse_window = self.helper.cursor();
+        self.mouse_logical = self.mouse_window.and_then(|pos| gfx.window_to_logical(pos));
     }
     /// Returns true if the MouseButton is down in the current frame.
     pub fn mouse_down(&self, button: MouseButton) -> bool {
-        self.helper.mouse_held(button)
+        self.mouse_held.contains(&button)
     }
     /// Returns true if the MouseButton is pressed.
     pub fn mouse_pressed(&self, button: MouseButton) -> bool {
-        self.helper.mouse_pressed(button)
+        self.mouse_pressed.contains(&button)
     }
     /// Returns true if the MouseButton is released in the current frame.
     pub fn mouse_released(&self, button: MouseButton) -> bool {
-        self.helper.mouse_released(button)
+        self.mouse_released.contains(&button)
     }
     /// Returns the current Mouse position as `f32 Tuple` containg X&Y cordinates. <br>
-    /// While window is unfocused it will return `None`
+    /// While window is unfocused or the mouse is outside of the canvas (eg. on the letterbox bars) it will return `None`
     /// _Note : Mouse Positions need to be precise thats why we didn't use Vec2 here!_
     pub fn mouse_pos(&self) -> Option<(f32, f32)> {
         self.mouse_logical
     }
+    /// Returns the Mouse position in physical window pixels, even when it's outside of the canvas. <br>
+    /// Use [Graphics::window_to_logical] to convert it.
+    pub fn mouse_window_pos(&self) -> Option<(f32, f32)> {
+        self.mouse_window
+    }
+
+    /// Returns how far the mouse moved this frame, zero if it didn't. <br>
+    /// Unlike [Input::mouse_pos] it doesn't stop at the edge of the window, use it for camera controls or drag to pan.
+    /// _Note : The units come straight from the mouse, usually physical pixels without acceleration._
+    pub fn mouse_delta(&self) -> (f32, f32) {
+        self.mouse_delta
+    }
+
+    /// Show or hide the cursor while it's over the window
+    pub fn set_cursor_visible(&mut self, visible: bool) {
+        self.cursor_changed |= self.cursor_visible != visible;
+        self.cursor_visible = visible;
+    }
+    pub fn cursor_visible(&self) -> bool {
+        self.cursor_visible
+    }
+    /// Keep the cursor inside the window. See [CursorGrab] <br>
+    /// Platforms that can't do the requested mode fall back to the other one, the grab is given back while the window is unfocused.
+    pub fn set_cursor_grab(&mut self, grab: CursorGrab) {
+        self.cursor_changed |= self.cursor_grab != grab;
+        self.cursor_grab = grab;
+    }
+    pub fn cursor_grab(&self) -> CursorGrab {
+        self.cursor_grab
+    }
+
+    /// Returns how far the mouse wheel or trackpad scrolled this frame, zero if it didn't
+    pub fn scroll_delta(&self) -> ScrollDelta {
+        self.scroll
+    }
+
+    // ? Gamepads
+
+    /// Returns the ids of every connected gamepad, lowest first
+    pub fn gamepads(&self) -> Vec<GamepadId> {
+        self.gamepads.ids()
+    }
+    /// Returns the name the gamepad reports, `None` if it isn't connected
+    pub fn gamepad_name(&self, id: GamepadId) -> Option<&str> {
+        self.gamepads.name(id)
+    }
+    /// Returns the gamepads that got connected this frame
+    pub fn gamepads_connected(&self) -> &[GamepadId] {
+        &self.gamepads.connected
+    }
+    /// Returns the gamepads that got disconnected this frame
+    pub fn gamepads_disconnected(&self) -> &[GamepadId] {
+        &self.gamepads.disconnected
+    }
+    /// Returns true while the button is held down
+    pub fn gamepad_down(&self, id: GamepadId, button: GamepadButton) -> bool {
+        self.gamepads.held(id, button)
+    }
+    /// Returns true only on the frame the button was pressed
+    pub fn gamepad_pressed(&self, id: GamepadId, button: GamepadButton) -> bool {
+        self.gamepads.pressed(id, button)
+    }
+    /// Returns true only on the frame the button was released
+    pub fn gamepad_released(&self, id: GamepadId, button: GamepadButton) -> bool {
+        self.gamepads.released(id, button)
+    }
+    /// Returns the position of a stick or trigger, 0.0 if the gamepad never reported it. <br>
+    /// Small values around the center are cut off, see [Input::set_gamepad_deadzone].
+    pub fn gamepad_axis(&self, id: GamepadId, axis: GamepadAxis) -> f32 {
+        self.gamepads.axis(id, axis)
+    }
+    /// Set how far sticks and triggers have to move before [Input::gamepad_axis] reports anything. <br>
+    /// Worn sticks drift a little around the center, defaults to 0.1
+    pub fn set_gamepad_deadzone(&mut self, deadzone: f32) {
+        self.gamepads.deadzone = deadzone.clamp(0.0, 0.99);
+    }
+
+    // ? Actions
+
+    /// Returns the bindings behind [Input::action_down] & co.
+    pub fn actions(&self) -> &ActionMap {
+        &self.actions
+    }
+    /// Change the bindings at runtime, eg. from a controls menu
+    pub fn actions_mut(&mut self) -> &mut ActionMap {
+        &mut self.actions
+    }
+    /// Returns true while any binding of the action is held down
+    pub fn action_down(&self, action: &str) -> bool {
+        self.actions.bindings(action).iter().any(|b| self.binding_down(*b))
+    }
+    /// Returns true only on the frame the action went down. <br>
+    /// Pressing a second binding while the first one is still held doesn't count.
+    pub fn action_pressed(&self, action: &str) -> bool {
+        let bindings = self.actions.bindings(action);
+        bindings.iter().any(|b| self.binding_pressed(*b))
+            && bindings.iter().all(|b| !self.binding_down(*b) || self.binding_pressed(*b))
+    }
+    /// Returns true only on the frame the last held binding of the action was released
+    pub fn action_released(&self, action: &str) -> bool {
+        let bindings = self.actions.bindings(action);
+        bindings.iter().any(|b| self.binding_released(*b)) && !bindings.iter().any(|b| self.binding_down(*b))
+    }
+    /// Returns something that was pressed this frame, handy for "press a key to rebind" menus
+    pub fn binding_pressed_any(&self) -> Option<Binding> {
+        let key = self.keys_pressed.iter().find_map(|key| match key {
+            PhysicalKey::Code(code) => Some(Binding::Key(*code)),
+            PhysicalKey::Unidentified(_) => None,
+        });
+        let mouse = || self.mouse_pressed.iter().next().map(|b| Binding::Mouse(*b));
+        key.or_else(mouse).or_else(|| self.gamepads.pressed_any().map(Binding::Gamepad))
+    }
+    /// Returns true while the binding is held down, gamepad buttons count on any gamepad
+    pub fn binding_down(&self, binding: Binding) -> bool {
+        match binding {
+            Binding::Key(key) => self.key_down(key),
+            Binding::Mouse(button) => self.mouse_down(button),
+            Binding::Gamepad(button) => self.gamepads().into_iter().any(|id| self.gamepad_down(id, button)),
+        }
+    }
+    /// Returns true only on the frame the binding was pressed
+    pub fn binding_pressed(&self, binding: Binding) -> bool {
+        match binding {
+            Binding::Key(key) => self.key_pressed(key),
+            Binding::Mouse(button) => self.mouse_pressed(button),
+            Binding::Gamepad(button) => self.gamepads().into_iter().any(|id| self.gamepad_pressed(id, button)),
+        }
+    }
+    /// Returns true only on the frame the binding was released
+    pub fn binding_released(&self, binding: Binding) -> bool {
+        match binding {
+            Binding::Key(key) => self.key_released(key),
+            Binding::Mouse(button) => self.mouse_released(button),
+            Binding::Gamepad(button) => self.gamepads().into_iter().any(|id| self.gamepad_released(id, button)),
+        }
+    }
 
     // ? Window Related
     /// Returns the window Size after a resize event.
@@ -112,3 +445,104 @@ impl Input {
         self.helper.close_requested()
     }
 }
+
+/// Sets come out in a random order, sort them so the same input always records the same way
+#[cfg(feature = "record")]
+fn sorted<T: Ord + Copy>(set: &HashSet<T>) -> Vec<T> {
+    let mut items: Vec<T> = set.iter().copied().collect();
+    items.sort_unstable();
+    items
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MemoryGamepads;
+    use winit::dpi::PhysicalPosition;
+
+    fn input() -> Input {
+        Input::new(Some(Box::new(MemoryGamepads::new())), ActionMap::new())
+    }
+
+    #[test]
+    fn scrolling_adds_up_until_the_frame_ends() {
+        let mut input = input();
+        input.process_scroll_event(&MouseScrollDelta::LineDelta(0.0, 1.0));
+        input.process_scroll_event(&MouseScrollDelta::LineDelta(-0.5, 2.0));
+        input.process_scroll_event(&MouseScrollDelta::PixelDelta(PhysicalPosition::new(3.0, -12.5)));
+        input.process_scroll_event(&MouseScrollDelta::PixelDelta(PhysicalPosition::new(1.5, 2.5)));
+        // Wheels count in lines and trackpads in pixels, neither is turned into the other
+        assert_eq!(input.scroll_delta(), ScrollDelta { lines: (-0.5, 3.0), pixels: (4.5, -10.0) });
+
+        input.reset_transient_state();
+        assert_eq!(input.scroll_delta(), ScrollDelta::default());
+
+        input.process_scroll_event(&MouseScrollDelta::LineDelta(0.0, -1.0));
+        assert_eq!(input.scroll_delta().lines, (0.0, -1.0));
+        assert_eq!(input.scroll_delta().pixels, (0.0, 0.0));
+    }
+
+    #[test]
+    fn typed_and_composed_text() {
+        let mut input = input();
+        let key = |input: &mut Input, code, text| input.process_key(PhysicalKey::Code(code), ElementState::Pressed, text);
+        key(&mut input, KeyCode::KeyH, Some("h"));
+        key(&mut input, KeyCode::KeyI, Some("i"));
+        // Control characters stay out of the text
+        key(&mut input, KeyCode::Enter, Some("\r"));
+        key(&mut input, KeyCode::Backspace, Some("\u{8}"));
+        key(&mut input, KeyCode::ShiftLeft, None);
+        input.process_ime_event(&Ime::Enabled);
+        input.process_ime_event(&Ime::Preedit("に".to_string(), Some((0, 3))));
+        input.process_ime_event(&Ime::Preedit("にほ".to_string(), Some((6, 6))));
+        assert_eq!(input.text_entered(), "hi");
+        assert_eq!(input.ime_preedit(), Some(("にほ", Some((6, 6)))));
+
+        // The preedit is still going on in the next frame, only the entered text is cleared
+        input.reset_transient_state();
+        assert_eq!(input.text_entered(), "");
+        assert_eq!(input.ime_preedit(), Some(("にほ", Some((6, 6)))));
+
+        input.process_ime_event(&Ime::Commit("日本".to_string()));
+        key(&mut input, KeyCode::Digit1, Some("!"));
+        assert_eq!(input.text_entered(), "日本!");
+        assert_eq!(input.ime_preedit(), None);
+
+        input.reset_transient_state();
+        input.process_ime_event(&Ime::Preedit("ご".to_string(), None));
+        input.process_ime_event(&Ime::Preedit(String::new(), None));
+        assert_eq!(input.ime_preedit(), None);
+        input.process_ime_event(&Ime::Preedit("ご".to_string(), None));
+        input.process_ime_event(&Ime::Disabled);
+        assert_eq!(input.ime_preedit(), None);
+        assert_eq!(input.text_entered(), "");
+    }
+
+    #[test]
+    fn mouse_motion() {
+        let mut input = input();
+        assert_eq!(input.mouse_delta(), (0.0, 0.0));
+
+        for delta in [(4.0, -1.0), (-1.5, 0.25), (0.5, 3.0)] {
+            input.process_device_event(&DeviceEvent::MouseMotion { delta });
+        }
+        // Other device events don't move anything
+        input.process_device_event(&DeviceEvent::MouseWheel { delta: MouseScrollDelta::LineDelta(0.0, 1.0) });
+        assert_eq!(input.mouse_delta(), (3.0, 2.25));
+        assert_eq!(input.scroll_delta(), ScrollDelta::default());
+
+        input.reset_transient_state();
+        assert_eq!(input.mouse_delta(), (0.0, 0.0));
+        input.process_device_event(&DeviceEvent::MouseMotion { delta: (-2.0, 0.0) });
+        assert_eq!(input.mouse_delta(), (-2.0, 0.0));
+
+        // Grabbing only tells the runtime to update the window, the delta keeps coming
+        input.cursor_changed = false;
+        input.set_cursor_grab(CursorGrab::Locked);
+        input.set_cursor_visible(false);
+        assert!(input.cursor_changed);
+        assert_eq!((input.cursor_grab(), input.cursor_visible()), (CursorGrab::Locked, false));
+        input.process_device_event(&DeviceEvent::MouseMotion { delta: (1.0, 1.0) });
+        assert_eq!(input.mouse_delta(), (-1.0, 1.0));
+    }
+}
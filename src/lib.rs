@@ -27,15 +27,32 @@ mod input;
 mod context;
 mod config;
 mod vector;
+mod scaling;
+mod postprocess;
+mod upscale;
+mod gamepad;
+mod actions;
 #[cfg(feature = "extension")]
 pub mod extensions;
+#[cfg(feature = "record")]
+pub mod record;
 
-pub use graphics::Graphics;
+pub use graphics::{Graphics, Connectivity, DrawCommand};
 pub use context::FrameContext;
 pub use color::Color;
+pub use text::{BitmapFont, CharGrid, Cell, HAlign, VAlign, TextAlign, TextStyle, StrokeStyle};
+#[cfg(feature = "ttf")]
+pub use text::{Font, TextMode};
 pub use config::Config;
-pub use input::Input;
+pub use input::{CursorGrab, Input, ScrollDelta};
 pub use vector::{Vec2,Rect};
+pub use scaling::ScalingMode;
+pub use postprocess::{PostEffect, FilterFn};
+pub use upscale::Upscaler;
+pub use gamepad::{GamepadId, GamepadButton, GamepadAxis, GamepadEvent, GamepadProvider, MemoryGamepads};
+#[cfg(feature = "gamepad")]
+pub use gamepad::GilrsGamepads;
+pub use actions::{ActionMap, Binding};
 
 // Re-Exports from winit events
 pub use winit::keyboard::KeyCode;
@@ -48,7 +65,8 @@ use winit::event_loop::EventLoop;
 
 
 /// Run the application with default configuration
-///```rust
+///```no_run
+/// use graphicility::run;
 /// run(|ctx|{
 ///   // your drawing code here
 /// });
@@ -62,7 +80,8 @@ where
 
 /// Run the application with custom configuration
 /// Example:
-/// ```rust
+/// ```no_run
+/// use graphicility::{run_with, Config};
 /// let config = Config::builder()
 ///    .with_title("My App")
 ///    .set_window_size((1024, 768))
@@ -82,3 +101,25 @@ where
     event_loop.run_app(&mut app).unwrap()
 }
 
+
+/// Play back a recording made with `Config::builder().record_to(..)`. <br>
+/// Each frame draws the recorded commands, the last frame stays on screen once the recording ends.
+///```no_run
+/// use graphicility::{replay_with, Config};
+/// replay_with(Config::default(), "glitch.rec").unwrap();
+/// ```
+#[cfg(feature = "record")]
+pub fn replay_with(config: Config, path: impl AsRef<std::path::Path>) -> std::io::Result<()> {
+    let mut frames = record::Replayer::open(path)?;
+    let mut last: Vec<DrawCommand> = Vec::new();
+
+    run_with(config, move |ctx| {
+        match frames.next() {
+            Some(Ok(frame)) => last = frame.commands,
+            Some(Err(err)) => eprintln!("Reading recorded frame failed: {}", err),
+            None => (),
+        }
+        ctx.graphics().replay(&last);
+    });
+    Ok(())
+}
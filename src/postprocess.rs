@@ -0,0 +1,245 @@
+/// Signature of a user defined filter: RGBA bytes of the frame, width, height
+pub type FilterFn = dyn Fn(&mut [u8], u32, u32);
+
+/// A filter that runs on the finished frame after every draw command is rasterized. <br>
+/// Effects are registered through the [Config](crate::Config) and run in the order they were added. <br>
+/// When an [Upscaler](crate::Upscaler) is set, effects run on the upscaled frame.
+///```no_run
+/// use graphicility::{Config, PostEffect};
+/// let config = Config::builder()
+///     .with_post_effect(PostEffect::Scanlines { intensity: 0.4 })
+///     .with_post_effect(PostEffect::Vignette { strength: 0.6 })
+///     .build();
+/// ```
+pub enum PostEffect {
+    /// Darkens every other row like an old CRT screen. `intensity` goes from 0.0 (off) to 1.0 (black lines)
+    Scanlines { intensity: f32 },
+    /// Darkens the corners of the frame. `strength` goes from 0.0 (off) to 1.0 (black corners)
+    Vignette { strength: f32 },
+    /// Adjusts the colors of the frame. `1.0` leaves the channel as is for all of the fields
+    ColorGrade {
+        brightness: f32,
+        contrast: f32,
+        saturation: f32,
+    },
+    /// Turns the frame black and white
+    Grayscale,
+    /// Moves the red channel `offset` pixels to the left and the blue channel to the right
+    ChromaticOffset { offset: i32 },
+    /// A user defined filter. Gets the RGBA bytes of the frame with its width & height. <br>
+    /// Effects only run when the frame changed, an effect that animates over time has to call
+    /// [Graphics::request_redraw](crate::Graphics::request_redraw) every frame
+    Custom(Box<FilterFn>),
+}
+
+impl PostEffect {
+    /// Create a [PostEffect::Custom] from a closure
+    pub fn custom<F: Fn(&mut [u8], u32, u32) + 'static>(filter: F) -> Self {
+        PostEffect::Custom(Box::new(filter))
+    }
+
+    /// Apply the effect to a RGBA frame of the given size
+    pub fn apply(&self, frame: &mut [u8], width: u32, height: u32) {
+        match self {
+            PostEffect::Scanlines { intensity } => scanlines(frame, width, *intensity),
+            PostEffect::Vignette { strength } => vignette(frame, width, height, *strength),
+            PostEffect::ColorGrade { brightness, contrast, saturation } => {
+                color_grade(frame, *brightness, *contrast, *saturation)
+            }
+            PostEffect::Grayscale => color_grade(frame, 1.0, 1.0, 0.0),
+            PostEffect::ChromaticOffset { offset } => chromatic_offset(frame, width, *offset),
+            PostEffect::Custom(filter) => filter(frame, width, height),
+        }
+    }
+}
+
+/// Run every effect in order, each one sees the output of the one before
+pub(crate) fn apply_all(effects: &[PostEffect], frame: &mut [u8], width: u32, height: u32) {
+    for effect in effects {
+        effect.apply(frame, width, height);
+    }
+}
+
+fn scanlines(frame: &mut [u8], width: u32, intensity: f32) {
+    let factor = 1.0 - intensity.clamp(0.0, 1.0);
+    let row_len = width as usize * 4;
+    if row_len == 0 {
+        return;
+    }
+
+    for row in frame.chunks_exact_mut(row_len).skip(1).step_by(2) {
+        for px in row.chunks_exact_mut(4) {
+            for c in &mut px[..3] {
+                *c = (*c as f32 * factor) as u8;
+            }
+        }
+    }
+}
+
+fn vignette(frame: &mut [u8], width: u32, height: u32, strength: f32) {
+    let strength = strength.clamp(0.0, 1.0);
+    let cx = width as f32 / 2.0;
+    let cy = height as f32 / 2.0;
+    // Distance from the center to a corner
+    let max_dist = (cx * cx + cy * cy).sqrt().max(1.0);
+
+    for (i, px) in frame.chunks_exact_mut(4).enumerate() {
+        let x = (i as u32 % width) as f32 + 0.5 - cx;
+        let y = (i as u32 / width) as f32 + 0.5 - cy;
+        let dist = (x * x + y * y).sqrt() / max_dist;
+        let factor = 1.0 - strength * dist * dist;
+
+        for c in &mut px[..3] {
+            *c = (*c as f32 * factor) as u8;
+        }
+    }
+}
+
+fn color_grade(frame: &mut [u8], brightness: f32, contrast: f32, saturation: f32) {
+    for px in frame.chunks_exact_mut(4) {
+        let (r, g, b) = (px[0] as f32, px[1] as f32, px[2] as f32);
+        // Rec. 601 luma
+        let luma = 0.299 * r + 0.587 * g + 0.114 * b;
+
+        for (i, c) in [r, g, b].into_iter().enumerate() {
+            let c = luma + (c - luma) * saturation;
+            let c = (c - 127.5) * contrast + 127.5;
+            px[i] = (c * brightness).clamp(0.0, 255.0) as u8;
+        }
+    }
+}
+
+fn chromatic_offset(frame: &mut [u8], width: u32, offset: i32) {
+    let row_len = width as usize * 4;
+    if row_len == 0 || offset == 0 {
+        return;
+    }
+    let width = width as i32;
+    let mut source = vec![0; row_len];
+
+    for row in frame.chunks_exact_mut(row_len) {
+        source.copy_from_slice(row);
+        for x in 0..width {
+            let red_x = (x + offset).clamp(0, width - 1) as usize;
+            let blue_x = (x - offset).clamp(0, width - 1) as usize;
+            let idx = x as usize * 4;
+            row[idx] = source[red_x * 4];
+            row[idx + 2] = source[blue_x * 4 + 2];
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    /// A `width` x `height` frame filled with one color
+    fn frame(width: u32, height: u32, rgba: [u8; 4]) -> Vec<u8> {
+        rgba.repeat((width * height) as usize)
+    }
+
+    fn pixel(frame: &[u8], width: u32, x: u32, y: u32) -> [u8; 4] {
+        let i = ((y * width + x) * 4) as usize;
+        frame[i..i + 4].try_into().unwrap()
+    }
+
+    #[test]
+    fn scanlines_darken_every_other_row() {
+        let mut buf = frame(2, 4, [200, 100, 50, 255]);
+        PostEffect::Scanlines { intensity: 0.5 }.apply(&mut buf, 2, 4);
+        for y in 0..4 {
+            let expected = if y % 2 == 0 { [200, 100, 50, 255] } else { [100, 50, 25, 255] };
+            assert_eq!(pixel(&buf, 2, 1, y), expected, "row {}", y);
+        }
+
+        let mut off = frame(2, 4, [200, 100, 50, 255]);
+        PostEffect::Scanlines { intensity: 0.0 }.apply(&mut off, 2, 4);
+        assert_eq!(off, frame(2, 4, [200, 100, 50, 255]));
+    }
+
+    #[test]
+    fn vignette_leaves_the_center_alone() {
+        let mut buf = frame(5, 5, [255, 255, 255, 255]);
+        PostEffect::Vignette { strength: 1.0 }.apply(&mut buf, 5, 5);
+
+        assert_eq!(pixel(&buf, 5, 2, 2), [255, 255, 255, 255]);
+        let edge = pixel(&buf, 5, 0, 2);
+        let corner = pixel(&buf, 5, 0, 0);
+        assert!(corner[0] < edge[0] && edge[0] < 255, "corner {:?}, edge {:?}", corner, edge);
+        // Same distance from the center, same darkness. Alpha is never touched
+        assert_eq!(pixel(&buf, 5, 4, 4), corner);
+        assert_eq!(pixel(&buf, 5, 2, 4), edge);
+        assert!(buf.chunks(4).all(|px| px[3] == 255));
+    }
+
+    #[test]
+    fn color_grading() {
+        let grade = |brightness, contrast, saturation| {
+            let mut buf = frame(1, 1, [200, 100, 50, 128]);
+            PostEffect::ColorGrade { brightness, contrast, saturation }.apply(&mut buf, 1, 1);
+            pixel(&buf, 1, 0, 0)
+        };
+        assert_eq!(grade(1.0, 1.0, 1.0), [200, 100, 50, 128]);
+        assert_eq!(grade(0.5, 1.0, 1.0), [100, 50, 25, 128]);
+        // Pushed away from the middle gray and clamped
+        assert_eq!(grade(1.0, 2.0, 1.0), [255, 72, 0, 128]);
+        // Rec. 601 luma of the color
+        assert_eq!(grade(1.0, 1.0, 0.0), [124, 124, 124, 128]);
+
+        let mut gray = frame(1, 1, [200, 100, 50, 128]);
+        PostEffect::Grayscale.apply(&mut gray, 1, 1);
+        assert_eq!(pixel(&gray, 1, 0, 0), grade(1.0, 1.0, 0.0));
+    }
+
+    #[test]
+    fn chromatic_offset_moves_red_and_blue_apart() {
+        let mut buf: Vec<u8> = (0..4).flat_map(|x| [10 * (x + 1), 7, x + 1, 255]).collect();
+        PostEffect::ChromaticOffset { offset: 1 }.apply(&mut buf, 4, 1);
+        let channel = |c: usize| buf.chunks(4).map(|px| px[c]).collect::<Vec<_>>();
+        // Edges repeat the last pixel instead of wrapping around
+        assert_eq!(channel(0), [20, 30, 40, 40]);
+        assert_eq!(channel(1), [7, 7, 7, 7]);
+        assert_eq!(channel(2), [1, 1, 2, 3]);
+    }
+
+    #[test]
+    fn custom_effects_get_the_frame_size() {
+        let seen = Rc::new(RefCell::new(None));
+        let inner = seen.clone();
+        let effect = PostEffect::custom(move |frame, width, height| {
+            *inner.borrow_mut() = Some((frame.len(), width, height));
+            frame[0] = 1;
+        });
+        let mut buf = frame(3, 2, [0; 4]);
+        effect.apply(&mut buf, 3, 2);
+        assert_eq!(*seen.borrow(), Some((24, 3, 2)));
+        assert_eq!(buf[0], 1);
+    }
+
+    #[test]
+    fn effects_chain_in_order() {
+        let brighten = || PostEffect::ColorGrade { brightness: 2.0, contrast: 1.0, saturation: 1.0 };
+        let darken = || PostEffect::ColorGrade { brightness: 0.5, contrast: 1.0, saturation: 1.0 };
+
+        // Brightening first clips at white, so the order shows in the result
+        let mut buf = frame(1, 1, [200, 60, 0, 255]);
+        apply_all(&[brighten(), darken()], &mut buf, 1, 1);
+        assert_eq!(buf, [127, 60, 0, 255]);
+
+        let mut buf = frame(1, 1, [200, 60, 0, 255]);
+        apply_all(&[darken(), brighten()], &mut buf, 1, 1);
+        assert_eq!(buf, [200, 60, 0, 255]);
+
+        let order = Rc::new(RefCell::new(Vec::new()));
+        let effects: Vec<_> = (0..3)
+            .map(|i| {
+                let order = order.clone();
+                PostEffect::custom(move |_, _, _| order.borrow_mut().push(i))
+            })
+            .collect();
+        apply_all(&effects, &mut frame(1, 1, [0; 4]), 1, 1);
+        assert_eq!(*order.borrow(), [0, 1, 2]);
+    }
+}
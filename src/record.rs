@@ -0,0 +1,384 @@
+//! Record the frames of an application to a file and replay them later. <br>
+//! Every frame is stored as one JSON line containing the delta time, an [InputSnapshot] and the [DrawCommand]s.
+//! A recording sent along with a bug report can be played back deterministically with [replay_with](crate::replay_with).
+//!
+//! Recordings can also drive the [Input](crate::Input) of the application instead of the keyboard and mouse,
+//! set them with `play_input_from`. Use `record_input_to` to leave the draw commands out and keep the file small.
+//!```no_run
+//! use graphicility::Config;
+//! let config = Config::builder()
+//!     .record_to("glitch.rec")
+//!     .build();
+//! graphicility::run_with(config, |ctx| {
+//!     // your drawing code here
+//! });
+//! ```
+//!```no_run
+//! use graphicility::Config;
+//! // Run the game on the recorded input over and over, eg. for an attract mode
+//! let config = Config::builder()
+//!     .play_input_from("attract.rec")
+//!     .set_playback_looping(true)
+//!     .build();
+//! graphicility::run_with(config, |ctx| {
+//!     // your drawing code here
+//! });
+//! ```
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use winit::event::MouseButton;
+use winit::keyboard::PhysicalKey;
+
+use crate::DrawCommand;
+use crate::input::ScrollDelta;
+
+/// State of the [Input](crate::Input) at the end of a frame
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct InputSnapshot {
+    pub keys_held: Vec<PhysicalKey>,
+    pub keys_pressed: Vec<PhysicalKey>,
+    pub keys_released: Vec<PhysicalKey>,
+    /// Mouse position in logical canvas coordinates
+    pub mouse_pos: Option<(f32, f32)>,
+    /// Mouse position in physical window pixels
+    pub mouse_window_pos: Option<(f32, f32)>,
+    pub mouse_held: Vec<MouseButton>,
+    pub mouse_pressed: Vec<MouseButton>,
+    pub mouse_released: Vec<MouseButton>,
+    pub scroll: ScrollDelta,
+    /// Raw mouse movement, see [Input::mouse_delta](crate::Input::mouse_delta)
+    pub mouse_delta: (f32, f32),
+    /// Text typed during the frame
+    pub text: String,
+}
+
+/// Everything that went into a single frame
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct FrameRecord {
+    pub dt: f64,
+    pub input: InputSnapshot,
+    /// Left out of recordings made with `record_input_to`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub commands: Vec<DrawCommand>,
+}
+
+/// Writes [FrameRecord]s to a file, or anything else that implements [Write]
+pub struct Recorder<W: Write = BufWriter<File>> {
+    writer: W,
+}
+
+impl Recorder {
+    /// Create a new recording, overwriting the file if it exists
+    pub fn create(path: impl AsRef<Path>) -> io::Result<Self> {
+        Ok(Self::new(BufWriter::new(File::create(path)?)))
+    }
+}
+
+impl<W: Write> Recorder<W> {
+    pub fn new(writer: W) -> Self {
+        Self { writer }
+    }
+    /// Append a frame to the recording
+    pub fn record(&mut self, frame: &FrameRecord) -> io::Result<()> {
+        serde_json::to_writer(&mut self.writer, frame)?;
+        self.writer.write_all(b"\n")
+    }
+    /// Make sure everything recorded so far is written to the file
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+    /// Stop recording and get the writer back
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+/// Reads [FrameRecord]s back from a recording, one frame per iteration
+pub struct Replayer<R: BufRead = BufReader<File>> {
+    lines: io::Lines<R>,
+}
+
+impl Replayer {
+    /// Open a recording made by the [Recorder]
+    pub fn open(path: impl AsRef<Path>) -> io::Result<Self> {
+        Ok(Self::new(BufReader::new(File::open(path)?)))
+    }
+}
+
+impl<R: BufRead> Replayer<R> {
+    pub fn new(reader: R) -> Self {
+        Self { lines: reader.lines() }
+    }
+}
+
+/// Feeds the recorded input back into the [Input](crate::Input), see `Config::builder().play_input_from(..)`
+pub(crate) struct InputPlayback {
+    path: PathBuf,
+    frames: Replayer,
+    looping: bool,
+}
+
+impl InputPlayback {
+    pub(crate) fn open(path: impl Into<PathBuf>, looping: bool) -> io::Result<Self> {
+        let path = path.into();
+        Ok(Self { frames: Replayer::open(&path)?, path, looping })
+    }
+
+    /// The next recorded frame, `None` once the recording is over
+    pub(crate) fn next_frame(&mut self) -> Option<io::Result<FrameRecord>> {
+        match self.frames.next() {
+            None if self.looping => {
+                self.frames = match Replayer::open(&self.path) {
+                    Ok(frames) => frames,
+                    Err(err) => return Some(Err(err)),
+                };
+                self.frames.next()
+            }
+            frame => frame,
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for Replayer<R> {
+    type Item = io::Result<FrameRecord>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let line = match self.lines.next()? {
+            Ok(line) => line,
+            Err(err) => return Some(Err(err)),
+        };
+        Some(serde_json::from_str(&line).map_err(io::Error::from))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ActionMap, Binding, Input, KeyCode, MemoryGamepads};
+    use winit::event::{DeviceEvent, ElementState, MouseScrollDelta};
+    use winit::event::ElementState::{Pressed, Released};
+
+    enum Event {
+        Key(KeyCode, ElementState, Option<&'static str>),
+        Mouse(MouseButton, ElementState),
+        Motion(f64, f64),
+        Scroll(f32),
+    }
+
+    fn script() -> Vec<Vec<Event>> {
+        use Event::*;
+        vec![
+            vec![Key(KeyCode::Space, Pressed, Some(" "))],
+            vec![Motion(3.0, -2.0), Motion(1.0, 0.5)],
+            // Jump is already down through Space, this isn't a new press
+            vec![Mouse(MouseButton::Other(5), Pressed)],
+            vec![Key(KeyCode::Space, Released, None), Scroll(-1.0)],
+            vec![Mouse(MouseButton::Other(5), Released)],
+            vec![Key(KeyCode::KeyA, Pressed, Some("a")), Key(KeyCode::KeyA, Released, None), Mouse(MouseButton::Left, Pressed)],
+            vec![Mouse(MouseButton::Left, Released), Mouse(MouseButton::Other(9), Pressed)],
+            vec![],
+        ]
+    }
+
+    fn input() -> Input {
+        let actions = ActionMap::new().with("jump", [Binding::Key(KeyCode::Space), Binding::Mouse(MouseButton::Other(5))]);
+        Input::new(Some(Box::new(MemoryGamepads::new())), actions)
+    }
+
+    /// Everything the application could ask the input during a frame
+    fn observe(input: &Input) -> String {
+        let keys = [KeyCode::Space, KeyCode::KeyA]
+            .map(|k| (input.key_down(k), input.key_pressed(k), input.key_released(k)));
+        let buttons = [MouseButton::Left, MouseButton::Other(5), MouseButton::Other(9)]
+            .map(|b| (input.mouse_down(b), input.mouse_pressed(b), input.mouse_released(b)));
+        let jump = (input.action_down("jump"), input.action_pressed("jump"), input.action_released("jump"));
+        format!(
+            "{:?} {:?} {:?} {:?} {:?} {:?}",
+            keys, buttons, jump, input.mouse_delta(), input.scroll_delta(), input.text_entered()
+        )
+    }
+
+    /// Run the script on live input, returns what every frame saw
+    fn record(path: &Path) -> Vec<(f64, String)> {
+        let mut recorder = Recorder::create(path).unwrap();
+        let mut input = input();
+        let mut seen = Vec::new();
+        for (n, events) in script().into_iter().enumerate() {
+            for event in events {
+                match event {
+                    Event::Key(key, state, text) => input.process_key(PhysicalKey::Code(key), state, text),
+                    Event::Mouse(button, state) => input.process_mouse_button(button, state),
+                    Event::Motion(x, y) => input.process_device_event(&DeviceEvent::MouseMotion { delta: (x, y) }),
+                    Event::Scroll(y) => input.process_scroll_event(&MouseScrollDelta::LineDelta(0.0, y)),
+                }
+            }
+            let dt = 0.016 + n as f64 / 1000.0;
+            seen.push((dt, observe(&input)));
+            recorder.record(&FrameRecord { dt, input: input.snapshot(), commands: Vec::new() }).unwrap();
+            input.reset_transient_state();
+        }
+        recorder.flush().unwrap();
+        seen
+    }
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("graphicility-{}-{}.rec", std::process::id(), name))
+    }
+
+    #[test]
+    fn playback_matches_the_recording() {
+        let path = temp_path("playback");
+        let seen = record(&path);
+
+        let mut playback = InputPlayback::open(&path, false).unwrap();
+        let mut input = input();
+        for (dt, expected) in &seen {
+            // Live events in between get overwritten by the recording
+            input.process_mouse_button(MouseButton::Right, Pressed);
+            let frame = playback.next_frame().unwrap().unwrap();
+            assert_eq!(frame.dt, *dt);
+            input.replay(frame.input);
+            assert!(input.is_replaying());
+            assert_eq!(&observe(&input), expected);
+            input.reset_transient_state();
+        }
+        assert!(playback.next_frame().is_none());
+
+        // The live mouse takes over again without anything stuck from the recording
+        input.stop_replay();
+        assert!(!input.is_replaying());
+        assert!(!input.mouse_down(MouseButton::Other(9)));
+
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn looping_playback_starts_over() {
+        let path = temp_path("looping");
+        let seen = record(&path);
+
+        let mut playback = InputPlayback::open(&path, true).unwrap();
+        let mut input = input();
+        for (dt, expected) in seen.iter().chain(&seen).chain(&seen) {
+            let frame = playback.next_frame().unwrap().unwrap();
+            assert_eq!(frame.dt, *dt);
+            input.replay(frame.input);
+            assert_eq!(&observe(&input), expected);
+            input.reset_transient_state();
+        }
+
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn the_same_input_records_the_same_bytes() {
+        let keys = [KeyCode::KeyQ, KeyCode::KeyW, KeyCode::KeyE, KeyCode::KeyR, KeyCode::KeyT, KeyCode::KeyY];
+        let buttons = [MouseButton::Left, MouseButton::Right, MouseButton::Middle, MouseButton::Other(4)];
+        let recorded = |reverse: bool| {
+            let mut input = input();
+            let mut order: Vec<usize> = (0..keys.len()).collect();
+            if reverse {
+                order.reverse();
+            }
+            for &n in &order {
+                input.process_key(PhysicalKey::Code(keys[n]), Pressed, None);
+                input.process_mouse_button(buttons[n % buttons.len()], Pressed);
+            }
+            serde_json::to_string(&input.snapshot()).unwrap()
+        };
+
+        let first = recorded(false);
+        assert_eq!(first, recorded(true));
+        assert!(first.starts_with(r#"{"keys_held":[{"Code":"KeyE"},{"Code":"KeyQ"},"#), "{}", first);
+    }
+
+    fn commands() -> Vec<DrawCommand> {
+        use crate::{Color, Connectivity, Vec2};
+        vec![
+            DrawCommand::Clear(Color::rgb(1, 2, 3)),
+            DrawCommand::Rect { pos: Vec2::new(-4, 5), size: Vec2::new(6, -7), color: Color::rgba(8, 9, 10, 0.5) },
+            DrawCommand::Text { pos: Vec2::new(1, 2), text: "Ünï \"quoted\"\n".to_string(), color: Color::WHITE, scale: (2, 3) },
+            DrawCommand::Mask { pos: Vec2::new(0, 0), size: Vec2::new(2, 2), alpha: vec![0, 64, 128, 255], color: Color::RED },
+            DrawCommand::FloodFill { pos: Vec2::new(3, 3), color: Color::BLUE, connectivity: Connectivity::Eight, tolerance: 12 },
+        ]
+    }
+
+    fn snapshot() -> InputSnapshot {
+        InputSnapshot {
+            keys_held: vec![PhysicalKey::Code(KeyCode::KeyW)],
+            keys_pressed: vec![PhysicalKey::Code(KeyCode::KeyW)],
+            mouse_pos: Some((1.5, 2.25)),
+            mouse_window_pos: Some((3.0, 4.5)),
+            mouse_held: vec![MouseButton::Other(7)],
+            mouse_released: vec![MouseButton::Left],
+            scroll: ScrollDelta { lines: (0.0, -1.0), pixels: (2.5, 0.0) },
+            mouse_delta: (-3.0, 4.0),
+            text: "w".to_string(),
+            ..InputSnapshot::default()
+        }
+    }
+
+    #[test]
+    fn frames_survive_the_json_lines() {
+        let frames = [
+            FrameRecord { dt: 0.016, input: snapshot(), commands: commands() },
+            FrameRecord { dt: 0.017, input: InputSnapshot::default(), commands: Vec::new() },
+            FrameRecord { dt: 0.1, input: snapshot(), commands: Vec::new() },
+        ];
+
+        let mut recorder = Recorder::new(Vec::new());
+        for frame in &frames {
+            recorder.record(frame).unwrap();
+        }
+        let bytes = recorder.into_inner();
+
+        let text = std::str::from_utf8(&bytes).unwrap();
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines.len(), frames.len());
+        assert!(lines[0].contains("\"commands\""));
+        // Empty command lists are left out of input only recordings
+        assert!(!lines[1].contains("\"commands\""));
+        assert!(!lines[2].contains("\"commands\""));
+
+        let read: Vec<FrameRecord> = Replayer::new(bytes.as_slice()).collect::<io::Result<_>>().unwrap();
+        assert_eq!(read, frames);
+    }
+
+    #[test]
+    fn broken_lines_are_errors() {
+        let good = serde_json::to_string(&FrameRecord { dt: 0.5, input: snapshot(), commands: Vec::new() }).unwrap();
+        // Input only frames leave the commands out, everything else has to be there
+        let partial = r#"{"dt":0.5,"input":{"keys_held":[],"keys_pressed":[],"keys_released":[],"mouse_pos":null,"mouse_window_pos":null,"mouse_held":[],"mouse_pressed":[],"mouse_released":[]}}"#;
+        let text = format!("{}\n{{\"dt\":0.5}}\nnot json\n{}\n", good, partial);
+
+        let frames: Vec<io::Result<FrameRecord>> = Replayer::new(text.as_bytes()).collect();
+        assert_eq!(frames.len(), 4);
+        assert_eq!(frames[0].as_ref().unwrap().input, snapshot());
+        for broken in &frames[1..] {
+            let err = broken.as_ref().unwrap_err();
+            assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        }
+    }
+
+    #[test]
+    fn graphics_replays_recorded_commands() {
+        use winit::dpi::{LogicalSize, PhysicalSize};
+        let mut gfx = crate::Graphics::new(LogicalSize::new(64, 48), PhysicalSize::new(128, 96), crate::ScalingMode::default(), 1);
+
+        let commands = commands();
+        gfx.begin_frame();
+        gfx.replay(&commands);
+        gfx.end_frame();
+        assert_eq!(gfx.commands(), commands.as_slice());
+        assert!(gfx.frame_changed());
+
+        // Replaying the same frame again is detected as unchanged
+        gfx.begin_frame();
+        gfx.replay(&commands);
+        gfx.end_frame();
+        assert!(!gfx.frame_changed());
+    }
+}
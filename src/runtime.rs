@@ -4,17 +4,19 @@ use winit::application::ApplicationHandler;
 use winit::dpi::{LogicalSize, PhysicalSize};
 use winit::event::WindowEvent;
 use winit::event_loop::ActiveEventLoop;
-use winit::window::{Window, WindowAttributes, WindowId};
+use winit::window::{CursorGrabMode, Window, WindowAttributes, WindowId};
 use winit_input_helper::WinitInputHelper;
 
 use crate::Config;
 use crate::backends::PixelsBackend;
 use crate::context::FrameContext;
 use crate::graphics::Graphics;
-use crate::input::Input;
+use crate::input::{CursorGrab, Input};
 
 #[cfg(feature = "extension")]
 use crate::extensions::Extension;
+#[cfg(feature = "record")]
+use crate::record::{FrameRecord, InputPlayback, Recorder};
 
 /// The main runtime struct that manages the application lifecycle
 /// It holds the configuration, window, graphics context, rendering backend, and the user-defined drawing function.
@@ -26,8 +28,14 @@ pub struct Runtime<F> {
     draw_fn: F,
     last_frame_time: Instant,
     input_stepped: bool,
+    /// Forces a redraw even if the frame didn't change (eg. after a resize)
+    needs_redraw: bool,
     #[cfg(feature = "extension")]
-    extensions: Vec<Box<dyn Extension>>
+    extensions: Vec<Box<dyn Extension>>,
+    #[cfg(feature = "record")]
+    recorder: Option<Recorder>,
+    #[cfg(feature = "record")]
+    playback: Option<InputPlayback>,
 }
 
 impl<F> Runtime<F>
@@ -37,25 +45,73 @@ where
     pub(crate) fn get_input_helper(&mut self) -> &mut WinitInputHelper {
         &mut self.context.as_mut().unwrap().inputs.helper
     }
+    #[cfg(feature = "record")]
+    fn create_recorder(config: &Config) -> Option<Recorder> {
+        let path = config.record_path.as_ref()?;
+        match Recorder::create(path) {
+            Ok(recorder) => Some(recorder),
+            Err(err) => {
+                eprintln!("Creating recording {} failed: {}", path.display(), err);
+                None
+            }
+        }
+    }
+    #[cfg(feature = "record")]
+    fn create_playback(config: &Config) -> Option<InputPlayback> {
+        let path = config.playback_path.as_ref()?;
+        match InputPlayback::open(path, config.playback_looping) {
+            Ok(playback) => Some(playback),
+            Err(err) => {
+                eprintln!("Opening recording {} failed: {}", path.display(), err);
+                None
+            }
+        }
+    }
+    /// Hand the cursor settings of the [Input] to the window
+    fn apply_cursor(window: &Window, input: &Input) {
+        window.set_cursor_visible(input.cursor_visible());
+
+        // Most platforms only support one of the two (Windows and X11 confine, macOS and the web lock),
+        // so try the other before giving up. Both keep the cursor in the window and mouse_delta works with either
+        let (mode, fallback) = match input.cursor_grab() {
+            CursorGrab::None => (CursorGrabMode::None, CursorGrabMode::None),
+            CursorGrab::Confined => (CursorGrabMode::Confined, CursorGrabMode::Locked),
+            CursorGrab::Locked => (CursorGrabMode::Locked, CursorGrabMode::Confined),
+        };
+        if let Err(err) = window.set_cursor_grab(mode).or_else(|_| window.set_cursor_grab(fallback)) {
+            eprintln!("Grabbing the cursor failed: {}", err);
+        }
+    }
     #[cfg(not(feature = "extension"))]
-    pub fn new(draw_fn: F, config: Config) -> Self {
+    pub fn new(draw_fn: F, mut config: Config) -> Self {
         let logical_size = LogicalSize::new(config.logical_width, config.logical_height);
         
         let graphics = Graphics::new(
             logical_size,
             PhysicalSize::new(config.window_width, config.window_height),
+            config.scaling_mode,
+            config.upscaler.factor(),
         );
 
-        let inputs = Input::new();
+        let inputs = Input::new(config.gamepad_provider.take(), std::mem::take(&mut config.actions));
+        #[cfg(feature = "record")]
+        let recorder = Self::create_recorder(&config);
+        #[cfg(feature = "record")]
+        let playback = Self::create_playback(&config);
 
         Self {
             config,
             window: None,
             context: Some(FrameContext::new(graphics, inputs)),
             backend: None,
-            draw_fn: draw_fn,
+            draw_fn,
             last_frame_time: Instant::now(),
             input_stepped: false,
+            needs_redraw: true,
+            #[cfg(feature = "record")]
+            recorder,
+            #[cfg(feature = "record")]
+            playback,
         }
     }
 
@@ -69,20 +125,31 @@ where
         let graphics = Graphics::new(
             logical_size,
             PhysicalSize::new(config.window_width, config.window_height),
+            config.scaling_mode,
+            config.upscaler.factor(),
         );
 
-        let inputs = Input::new();
+        let inputs = Input::new(config.gamepad_provider.take(), std::mem::take(&mut config.actions));
+        #[cfg(feature = "record")]
+        let recorder = Self::create_recorder(&config);
+        #[cfg(feature = "record")]
+        let playback = Self::create_playback(&config);
 
         Self {
             config,
             window: None,
             context: Some(FrameContext::new(graphics, inputs)),
             backend: None,
-            draw_fn: draw_fn,
+            draw_fn,
             last_frame_time: Instant::now(),
             input_stepped: false,
+            needs_redraw: true,
             #[cfg(feature = "extension")]
-            extensions: extensions
+            extensions,
+            #[cfg(feature = "record")]
+            recorder,
+            #[cfg(feature = "record")]
+            playback,
         }
     }
 }
@@ -100,11 +167,27 @@ where
             .with_inner_size(PhysicalSize::new(config.window_width, config.window_height));
 
         let window = event_loop.create_window(attrs).unwrap();
+        window.set_ime_allowed(config.ime_allowed);
 
         let physical_size = window.inner_size();
         let logical_size = LogicalSize::new(config.logical_width, config.logical_height);
 
-        self.backend = Some(PixelsBackend::new(&window, physical_size, logical_size));
+        // Effects can't be cloned, when the app resumes again (eg. on Android) they move over from the old backend
+        let post_effects = match self.backend.take() {
+            Some(backend) => backend.into_post_effects(),
+            None => std::mem::take(&mut self.config.post_effects),
+        };
+        let config = &self.config;
+
+        self.backend = Some(PixelsBackend::new(
+            &window,
+            physical_size,
+            logical_size,
+            config.scaling_mode,
+            config.letterbox_color,
+            config.upscaler,
+            post_effects,
+        ));
         self.window = Some(window);
     }
 
@@ -122,11 +205,20 @@ where
                 .inputs
                 .process_key_event(event);
         }
+        if let WindowEvent::MouseInput { state, button, .. } = &event {
+            self.context.as_mut().unwrap().inputs.process_mouse_button(*button, *state);
+        }
+        if let WindowEvent::MouseWheel { delta, .. } = &event {
+            self.context.as_mut().unwrap().inputs.process_scroll_event(delta);
+        }
+        if let WindowEvent::Ime(ime) = &event {
+            self.context.as_mut().unwrap().inputs.process_ime_event(ime);
+        }
 
         if self.get_input_helper().process_window_event(&event) {
             let context = self.context.as_mut().unwrap();
             let renderer = self.backend.as_mut().unwrap();
-            renderer.render(context.gfx.commands())
+            renderer.render(context.gfx.commands(), context.gfx.generation())
         }
         match event {
             WindowEvent::CloseRequested => {
@@ -144,6 +236,11 @@ where
                 let ctx = self.context.as_mut().unwrap();
                 ctx.gfx.window_width = physical_size.width;
                 ctx.gfx.window_height = physical_size.height;
+                self.needs_redraw = true;
+            }
+            // The grab is lost while the window is unfocused
+            WindowEvent::Focused(true) => {
+                self.context.as_mut().unwrap().inputs.cursor_changed = true;
             }
 
             _ => (),
@@ -156,6 +253,7 @@ where
         _: winit::event::DeviceId,
         event: winit::event::DeviceEvent,
     ) {
+        self.context.as_mut().unwrap().inputs.process_device_event(&event);
         self.get_input_helper().process_device_event(&event);
     }
 
@@ -179,32 +277,81 @@ where
             };
 
             if should_run {
-                let mut context = self.context.as_mut().unwrap();
+                let context = self.context.as_mut().unwrap();
                 context.dt = elapsed.as_secs_f64().min(0.1);
                 self.last_frame_time = Instant::now();
 
                 context.inputs.update_mouse_mapping(&context.gfx);
+                context.inputs.gamepads.poll();
+
+                #[cfg(feature = "record")]
+                if let Some(playback) = &mut self.playback {
+                    match playback.next_frame() {
+                        Some(Ok(frame)) => {
+                            context.dt = frame.dt;
+                            context.inputs.replay(frame.input);
+                        }
+                        end => {
+                            if let Some(Err(err)) = end {
+                                eprintln!("Reading recorded input failed: {}", err);
+                            }
+                            context.inputs.stop_replay();
+                            self.playback = None;
+                        }
+                    }
+                }
                 context.gfx.begin_frame();
                 
 
                 #[cfg(feature = "extension")]
                 for ext in &mut self.extensions{
-                    ext.pre_draw(&mut context);
+                    ext.pre_draw(context);
                 }
 
-                (self.draw_fn)(&mut context);
+                (self.draw_fn)(context);
 
                 #[cfg(feature = "extension")]
                 for ext in &mut self.extensions{
-                    ext.post_draw(&mut context);
+                    ext.post_draw(context);
                 }
+                context.gfx.end_frame();
+
+                if context.inputs.cursor_changed {
+                    context.inputs.cursor_changed = false;
+                    Self::apply_cursor(win, &context.inputs);
+                }
+
+                #[cfg(feature = "record")]
+                if let Some(recorder) = &mut self.recorder {
+                    let frame = FrameRecord {
+                        dt: context.dt,
+                        input: context.inputs.snapshot(),
+                        commands: if self.config.record_commands {
+                            context.gfx.commands().to_vec()
+                        } else {
+                            Vec::new()
+                        },
+                    };
+                    // Flushed every frame so a crash doesn't take the last frames with it
+                    if let Err(err) = recorder.record(&frame).and_then(|_| recorder.flush()) {
+                        eprintln!("Recording frame failed: {}", err);
+                        self.recorder = None;
+                    }
+                }
+
                 context.inputs.helper.end_step();
                 context.inputs.reset_transient_state();
 
                 // Flag Reset for the new frame
                 self.input_stepped = false;
 
-                win.request_redraw();
+                // Presenting the exact same frame again is wasted work.
+                // Without a target fps the redraws are what keep the loop running, so those always go through
+                // and the backend only skips rasterizing
+                if self.needs_redraw || context.gfx.frame_changed() || self.config.target_fps.is_none() {
+                    self.needs_redraw = false;
+                    win.request_redraw();
+                }
             } else if let Some(target_fps) = self.config.target_fps {
                 let frame_duration = Duration::from_secs_f64(1.0 / target_fps as f64);
                 event_loop.set_control_flow(winit::event_loop::ControlFlow::WaitUntil(
@@ -0,0 +1,180 @@
+/// Controls how the logical canvas is mapped onto the physical window. <br>
+/// **Defaults to [ScalingMode::Integer]**
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+pub enum ScalingMode {
+    /// Pixel perfect scaling. The canvas is only scaled by whole numbers (1x, 2x, 3x...),
+    /// the rest of the window is filled with the letterbox color.
+    #[default]
+    Integer,
+    /// Scale as much as possible while keeping the aspect ratio,
+    /// the rest of the window is filled with the letterbox color.
+    Fit,
+    /// Stretch the canvas to cover the whole window. Aspect ratio is not preserved.
+    Stretch,
+    /// Cover the whole window while keeping the aspect ratio, the overflowing parts of the canvas are cropped.
+    Fill,
+}
+
+/// The area of the window the logical canvas gets drawn into (in physical pixels). <br>
+/// Might be bigger than the window itself when using [ScalingMode::Fill].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub(crate) struct Viewport {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+impl Viewport {
+    /// Calculate the viewport for a given logical and window size
+    pub fn new(mode: ScalingMode, logical_size: (u32, u32), window_size: (u32, u32)) -> Self {
+        let (lw, lh) = (logical_size.0.max(1) as f32, logical_size.1.max(1) as f32);
+        let (ww, wh) = (window_size.0 as f32, window_size.1 as f32);
+
+        // The side that touches the window takes its size as is, scaling back and forth can land a hair past it
+        let (width, height) = match mode {
+            ScalingMode::Integer => {
+                // Same as pixels: never go below 1x even if the window is smaller
+                let scale = (ww / lw).min(wh / lh).floor().max(1.0);
+                (lw * scale, lh * scale)
+            }
+            ScalingMode::Fit if ww / lw <= wh / lh => (ww, lh * ww / lw),
+            ScalingMode::Fit => (lw * wh / lh, wh),
+            ScalingMode::Stretch => (ww, wh),
+            ScalingMode::Fill if ww / lw >= wh / lh => (ww, lh * ww / lw),
+            ScalingMode::Fill => (lw * wh / lh, wh),
+        };
+
+        Viewport {
+            // Keep the canvas on whole pixels so nearest sampling doesn't get uneven
+            x: ((ww - width) / 2.0).floor(),
+            y: ((wh - height) / 2.0).floor(),
+            width,
+            height,
+        }
+    }
+
+    /// The visible part of the viewport clipped to the window as `(x, y, width, height)`
+    pub fn clip_rect(&self, window_size: (u32, u32)) -> (u32, u32, u32, u32) {
+        let x0 = self.x.max(0.0);
+        let y0 = self.y.max(0.0);
+        let x1 = (self.x + self.width).min(window_size.0 as f32);
+        let y1 = (self.y + self.height).min(window_size.1 as f32);
+
+        if x1 <= x0 || y1 <= y0 {
+            return (0, 0, 0, 0);
+        }
+        (x0 as u32, y0 as u32, (x1 - x0) as u32, (y1 - y0) as u32)
+    }
+
+    /// Column major transform that maps the full screen triangle onto the viewport
+    pub fn transform(&self, window_size: (u32, u32)) -> [f32; 16] {
+        let (ww, wh) = (window_size.0.max(1) as f32, window_size.1.max(1) as f32);
+
+        let sx = self.width / ww;
+        let sy = self.height / wh;
+        let tx = (self.x + self.width / 2.0) / ww * 2.0 - 1.0;
+        let ty = 1.0 - (self.y + self.height / 2.0) / wh * 2.0;
+
+        #[rustfmt::skip]
+        let transform = [
+            sx,  0.0, 0.0, 0.0,
+            0.0, sy,  0.0, 0.0,
+            0.0, 0.0, 1.0, 0.0,
+            tx,  ty,  0.0, 1.0,
+        ];
+        transform
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Graphics;
+    use winit::dpi::{LogicalSize, PhysicalSize};
+
+    const LOGICAL: (u32, u32) = (320, 180);
+    /// Odd sizes in both directions that none of the modes divide evenly
+    const WINDOW: (u32, u32) = (1001, 767);
+    const MODES: [ScalingMode; 4] = [ScalingMode::Integer, ScalingMode::Fit, ScalingMode::Stretch, ScalingMode::Fill];
+
+    fn assert_close(a: (f32, f32), b: (f32, f32)) {
+        assert!((a.0 - b.0).abs() < 1e-3 && (a.1 - b.1).abs() < 1e-3, "{:?} != {:?}", a, b);
+    }
+
+    fn assert_viewport(mode: ScalingMode, window: (u32, u32), expected: (f32, f32, f32, f32)) {
+        let vp = Viewport::new(mode, LOGICAL, window);
+        assert_close((vp.x, vp.y), (expected.0, expected.1));
+        assert_close((vp.width, vp.height), (expected.2, expected.3));
+    }
+
+    #[test]
+    fn integer() {
+        assert_viewport(ScalingMode::Integer, WINDOW, (20.0, 113.0, 960.0, 540.0));
+        assert_eq!(Viewport::new(ScalingMode::Integer, LOGICAL, WINDOW).clip_rect(WINDOW), (20, 113, 960, 540));
+        // Never below 1x, a tiny window only shows the middle of the canvas
+        assert_viewport(ScalingMode::Integer, (199, 101), (-61.0, -40.0, 320.0, 180.0));
+        assert_eq!(Viewport::new(ScalingMode::Integer, LOGICAL, (199, 101)).clip_rect((199, 101)), (0, 0, 199, 101));
+    }
+
+    #[test]
+    fn fit() {
+        let scale = 1001.0 / 320.0;
+        assert_viewport(ScalingMode::Fit, WINDOW, (0.0, 101.0, 1001.0, 180.0 * scale));
+        // Too tall a window letterboxes at the top and bottom, too wide one at the sides
+        assert_viewport(ScalingMode::Fit, (333, 999), (0.0, 405.0, 333.0, 187.3125));
+        assert_viewport(ScalingMode::Fit, (999, 91), (418.0, 0.0, 320.0 * 91.0 / 180.0, 91.0));
+    }
+
+    #[test]
+    fn stretch() {
+        for window in [WINDOW, (333, 999), (1, 1)] {
+            let vp = Viewport::new(ScalingMode::Stretch, LOGICAL, window);
+            assert_viewport(ScalingMode::Stretch, window, (0.0, 0.0, window.0 as f32, window.1 as f32));
+            assert_eq!(vp.clip_rect(window), (0, 0, window.0, window.1));
+        }
+    }
+
+    #[test]
+    fn fill() {
+        let scale = 767.0 / 180.0;
+        let width = 320.0 * scale;
+        assert_viewport(ScalingMode::Fill, WINDOW, (((1001.0 - width) / 2.0f32).floor(), 0.0, width, 767.0));
+        // Cropped on both sides, but the visible part still covers the window
+        assert_eq!(Viewport::new(ScalingMode::Fill, LOGICAL, WINDOW).clip_rect(WINDOW), (0, 0, 1001, 767));
+    }
+
+    #[test]
+    fn zero_sized_window() {
+        for mode in MODES {
+            assert_eq!(Viewport::new(mode, LOGICAL, (0, 0)).clip_rect((0, 0)), (0, 0, 0, 0));
+        }
+    }
+
+    #[test]
+    fn window_and_logical_positions_are_inverses() {
+        for mode in MODES {
+            for upscale in [1, 2] {
+                let gfx = Graphics::new(LogicalSize::new(LOGICAL.0, LOGICAL.1), PhysicalSize::new(WINDOW.0, WINDOW.1), mode, upscale);
+                for pos in [(0.0, 0.0), (17.5, 33.25), (160.0, 90.0), (319.5, 179.5)] {
+                    let window = gfx.logical_to_window(pos);
+                    assert_close(gfx.window_to_logical(window).unwrap(), pos);
+                }
+
+                let vp = Viewport::new(mode, (LOGICAL.0 * upscale, LOGICAL.1 * upscale), WINDOW);
+                let (x, y, w, h) = vp.clip_rect(WINDOW);
+                for pos in [(x as f32 + 0.5, y as f32 + 0.5), ((x + w / 3) as f32, (y + h / 2) as f32)] {
+                    let logical = gfx.window_to_logical(pos).unwrap();
+                    assert_close(gfx.logical_to_window(logical), pos);
+                }
+            }
+        }
+
+        // The letterbox bars aren't on the canvas
+        let gfx = Graphics::new(LogicalSize::new(LOGICAL.0, LOGICAL.1), PhysicalSize::new(WINDOW.0, WINDOW.1), ScalingMode::Integer, 1);
+        assert_eq!(gfx.window_to_logical((5.0, 5.0)), None);
+        assert_eq!(gfx.window_to_logical((19.9, 500.0)), None);
+        assert_eq!(gfx.window_to_logical((980.0, 300.0)), None);
+        assert!(gfx.window_to_logical((20.0, 113.0)).is_some());
+    }
+}
@@ -0,0 +1,569 @@
+use std::collections::HashMap;
+use std::io;
+use std::path::Path;
+use std::sync::Arc;
+
+use super::TextMask;
+use crate::vector::Vec2;
+
+const PSF1_MAGIC: [u8; 2] = [0x36, 0x04];
+const PSF2_MAGIC: [u8; 4] = [0x72, 0xb5, 0x4a, 0x86];
+/// Fonts claiming bigger glyphs, offsets or advances than this are treated as broken
+const MAX_METRIC: i32 = 4096;
+
+/// A pixel font loaded from a BDF or PSF (PSF1/PSF2, the Linux console format) file. <br>
+/// Set it with [Graphics::set_font](crate::Graphics::set_font) and [Graphics::text](crate::Graphics::text) draws with it.
+/// Works for fixed cells like 6x10 or 8x16 as well as proportional fonts. <br>
+/// Cloning is cheap, clones share the glyphs.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BitmapFont {
+    inner: Arc<FontData>,
+}
+
+#[derive(Debug, PartialEq)]
+struct FontData {
+    glyphs: HashMap<char, Glyph>,
+    /// Rows from the top of a line to the baseline
+    ascent: i32,
+    line_height: i32,
+    /// How far the cursor moves for characters the font doesn't have
+    default_advance: i32,
+}
+
+#[derive(Debug, PartialEq)]
+struct Glyph {
+    width: usize,
+    height: usize,
+    /// Offset of the bitmap from the pen position, `y` counts down from the top of the line
+    offset: (i32, i32),
+    advance: i32,
+    /// One byte per pixel, 1 means set
+    pixels: Vec<u8>,
+}
+
+impl BitmapFont {
+    /// Load a font from a .bdf, .psf or .psfu file, the format is picked from the file contents
+    pub fn from_file(path: impl AsRef<Path>) -> io::Result<Self> {
+        let bytes = std::fs::read(path)?;
+        if bytes.starts_with(&PSF1_MAGIC) || bytes.starts_with(&PSF2_MAGIC) {
+            Self::from_psf(&bytes)
+        } else {
+            Self::from_bdf(&bytes)
+        }
+    }
+
+    /// Load a PSF1 or PSF2 font. <br>
+    /// Glyphs are mapped through the unicode table if the font has one, otherwise glyph `n` is the character `n`.
+    pub fn from_psf(bytes: &[u8]) -> io::Result<Self> {
+        let (width, height, count, glyph_size, glyphs_start, table) = if bytes.starts_with(&PSF2_MAGIC) {
+            let field = |n: usize| -> io::Result<usize> {
+                let raw = bytes.get(n * 4..n * 4 + 4).ok_or_else(|| invalid("PSF2 header is cut off"))?;
+                Ok(u32::from_le_bytes(raw.try_into().unwrap()) as usize)
+            };
+            let (header_size, flags, count, glyph_size, height, width) =
+                (field(2)?, field(3)?, field(4)?, field(5)?, field(6)?, field(7)?);
+            (width, height, count, glyph_size, header_size, flags & 1 != 0)
+        } else if bytes.starts_with(&PSF1_MAGIC) && bytes.len() >= 4 {
+            let mode = bytes[2];
+            let count = if mode & 0x01 != 0 { 512 } else { 256 };
+            let height = bytes[3] as usize;
+            (8, height, count, height, 4, mode & 0x06 != 0)
+        } else {
+            return Err(invalid("not a PSF font"));
+        };
+
+        if width > MAX_METRIC as usize || height > MAX_METRIC as usize {
+            return Err(invalid("PSF glyphs are too big"));
+        }
+        let row_bytes = width.div_ceil(8);
+        if width == 0 || height == 0 || glyph_size < row_bytes * height {
+            return Err(invalid("PSF glyph size doesn't match its width and height"));
+        }
+        let glyphs_end = count
+            .checked_mul(glyph_size)
+            .and_then(|size| size.checked_add(glyphs_start))
+            .ok_or_else(|| invalid("PSF glyph count is too big"))?;
+        let glyph_data = bytes
+            .get(glyphs_start..glyphs_end)
+            .ok_or_else(|| invalid("PSF glyph data is cut off"))?;
+
+        let glyph = |n: usize| {
+            let data = &glyph_data[n * glyph_size..(n + 1) * glyph_size];
+            Glyph {
+                width,
+                height,
+                offset: (0, 0),
+                advance: width as i32,
+                pixels: unpack_rows(data, width, height),
+            }
+        };
+
+        let mut glyphs = HashMap::new();
+        if table {
+            let chars = if bytes.starts_with(&PSF2_MAGIC) {
+                psf2_table(&bytes[glyphs_end..], count)
+            } else {
+                psf1_table(&bytes[glyphs_end..], count)
+            };
+            for (n, chars) in chars.into_iter().enumerate() {
+                for c in chars {
+                    glyphs.insert(c, glyph(n));
+                }
+            }
+        } else {
+            for n in 0..count {
+                if let Some(c) = char::from_u32(n as u32) {
+                    glyphs.insert(c, glyph(n));
+                }
+            }
+        }
+
+        Ok(Self {
+            inner: Arc::new(FontData {
+                glyphs,
+                ascent: height as i32,
+                line_height: height as i32,
+                default_advance: width as i32,
+            }),
+        })
+    }
+
+    /// Load a font in the Glyph Bitmap Distribution Format (.bdf)
+    pub fn from_bdf(bytes: &[u8]) -> io::Result<Self> {
+        let text = std::str::from_utf8(bytes).map_err(|_| invalid("BDF font isn't valid text"))?;
+        let mut lines = text.lines().map(str::trim);
+        if !lines.next().is_some_and(|l| l.starts_with("STARTFONT")) {
+            return Err(invalid("not a BDF font"));
+        }
+
+        let mut bounding_box = None;
+        let mut ascent = None;
+        let mut descent = None;
+        let mut glyphs = HashMap::new();
+
+        while let Some(line) = lines.next() {
+            let (keyword, args) = line.split_once(' ').unwrap_or((line, ""));
+            match keyword {
+                "FONTBOUNDINGBOX" => bounding_box = Some(metrics::<4>(args)?),
+                "FONT_ASCENT" => ascent = Some(metrics::<1>(args)?[0]),
+                "FONT_DESCENT" => descent = Some(metrics::<1>(args)?[0]),
+                "STARTCHAR" => {
+                    let mut encoding = None;
+                    let mut advance = None;
+                    let mut bbx = None;
+                    for line in lines.by_ref() {
+                        let (keyword, args) = line.split_once(' ').unwrap_or((line, ""));
+                        match keyword {
+                            "ENCODING" => encoding = Some(numbers::<1>(args)?[0]),
+                            "DWIDTH" => advance = Some(metrics::<1>(args)?[0]),
+                            "BBX" => bbx = Some(metrics::<4>(args)?),
+                            "BITMAP" => break,
+                            _ => {}
+                        }
+                    }
+
+                    let [w, h, x_off, y_off] = bbx.or(bounding_box).ok_or_else(|| invalid("BDF glyph without a BBX"))?;
+                    let (width, height) = (w.max(0) as usize, h.max(0) as usize);
+                    let mut data = Vec::with_capacity(width.div_ceil(8) * height);
+                    for line in lines.by_ref() {
+                        if line == "ENDCHAR" {
+                            break;
+                        }
+                        if !line.is_ascii() {
+                            return Err(invalid("BDF bitmap row isn't hex"));
+                        }
+                        // Rows are padded to whole bytes
+                        let mut row = (0..line.len() / 2)
+                            .map(|i| u8::from_str_radix(&line[i * 2..i * 2 + 2], 16))
+                            .collect::<Result<Vec<_>, _>>()
+                            .map_err(|_| invalid("BDF bitmap row isn't hex"))?;
+                        row.resize(width.div_ceil(8), 0);
+                        data.extend(row);
+                    }
+                    data.resize(width.div_ceil(8) * height, 0);
+
+                    // -1 marks glyphs that aren't mapped to a character
+                    let Some(c) = encoding.and_then(|e| u32::try_from(e).ok()).and_then(char::from_u32) else {
+                        continue;
+                    };
+                    glyphs.insert(c, Glyph {
+                        width,
+                        height,
+                        // Relative to the baseline for now, moved below once the ascent is known
+                        offset: (x_off, -(y_off + h)),
+                        advance: advance.unwrap_or(w),
+                        pixels: unpack_rows(&data, width, height),
+                    });
+                }
+                _ => {}
+            }
+        }
+
+        let [bb_w, bb_h, _, bb_y] = bounding_box.ok_or_else(|| invalid("BDF font without a FONTBOUNDINGBOX"))?;
+        let ascent = ascent.unwrap_or(bb_h + bb_y);
+        let descent = descent.unwrap_or(-bb_y);
+        for glyph in glyphs.values_mut() {
+            glyph.offset.1 += ascent;
+        }
+
+        Ok(Self {
+            inner: Arc::new(FontData {
+                glyphs,
+                ascent,
+                line_height: ascent + descent,
+                default_advance: bb_w,
+            }),
+        })
+    }
+
+    /// Rows from the top of a line to the baseline
+    pub fn ascent(&self) -> i32 {
+        self.inner.ascent
+    }
+    /// Height of a line in pixels
+    pub fn line_height(&self) -> i32 {
+        self.inner.line_height
+    }
+    /// Returns true if the font has a glyph for `c`
+    pub fn has_glyph(&self, c: char) -> bool {
+        self.inner.glyphs.contains_key(&c)
+    }
+
+    /// Width of a line of text in pixels
+    pub fn text_width(&self, text: &str) -> i32 {
+        text.chars()
+            .map(|c| self.inner.glyphs.get(&c).map_or(self.inner.default_advance, |g| g.advance))
+            .sum()
+    }
+
+    /// Lay out a line of text, the top of the line sits at y 0 and the text starts at x 0
+    pub(crate) fn rasterize(&self, text: &str) -> TextMask {
+        let mut placed = Vec::new();
+        let mut pen_x = 0;
+        for c in text.chars() {
+            match self.inner.glyphs.get(&c) {
+                Some(glyph) => {
+                    placed.push((pen_x + glyph.offset.0, glyph.offset.1, glyph));
+                    pen_x += glyph.advance;
+                }
+                None => pen_x += self.inner.default_advance,
+            }
+        }
+        placed.retain(|(_, _, g)| g.width > 0 && g.height > 0);
+        if placed.is_empty() {
+            return TextMask::empty();
+        }
+
+        let left = placed.iter().map(|(x, _, _)| *x).min().unwrap_or(0);
+        let top = placed.iter().map(|(_, y, _)| *y).min().unwrap_or(0);
+        let right = placed.iter().map(|(x, _, g)| x + g.width as i32).max().unwrap_or(0);
+        let bottom = placed.iter().map(|(_, y, g)| y + g.height as i32).max().unwrap_or(0);
+        let (width, height) = ((right - left) as usize, (bottom - top) as usize);
+
+        let mut alpha = vec![0u8; width * height];
+        for (x, y, glyph) in placed {
+            let (gx, gy) = ((x - left) as usize, (y - top) as usize);
+            for (row, line) in glyph.pixels.chunks_exact(glyph.width).enumerate() {
+                let start = (gy + row) * width + gx;
+                for (dst, src) in alpha[start..start + line.len()].iter_mut().zip(line) {
+                    if *src != 0 {
+                        *dst = 255;
+                    }
+                }
+            }
+        }
+
+        TextMask {
+            offset: Vec2::new(left, top),
+            size: Vec2::new(width as i32, height as i32),
+            alpha,
+        }
+    }
+}
+
+fn invalid(msg: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+/// Parse the first `N` whitespace separated numbers of a BDF line
+fn numbers<const N: usize>(args: &str) -> io::Result<[i32; N]> {
+    let mut out = [0; N];
+    let mut parts = args.split_whitespace();
+    for n in &mut out {
+        *n = parts
+            .next()
+            .and_then(|p| p.parse().ok())
+            .ok_or_else(|| invalid("BDF line is missing a number"))?;
+    }
+    Ok(out)
+}
+
+/// [numbers] that describe sizes or positions, anything past [MAX_METRIC] is rejected so the math on them can't overflow
+fn metrics<const N: usize>(args: &str) -> io::Result<[i32; N]> {
+    let values = numbers::<N>(args)?;
+    if values.iter().any(|n| n.unsigned_abs() > MAX_METRIC as u32) {
+        return Err(invalid("BDF metric is out of range"));
+    }
+    Ok(values)
+}
+
+/// Turn rows of packed bits (most significant bit is the leftmost pixel) into one byte per pixel
+fn unpack_rows(data: &[u8], width: usize, height: usize) -> Vec<u8> {
+    let row_bytes = width.div_ceil(8);
+    let mut pixels = Vec::with_capacity(width * height);
+    for row in data.chunks_exact(row_bytes).take(height) {
+        for x in 0..width {
+            pixels.push((row[x / 8] >> (7 - x % 8)) & 1);
+        }
+    }
+    pixels
+}
+
+/// Characters of each glyph, entries are UCS-2 and end with 0xFFFF. Sequences after 0xFFFE are skipped
+fn psf1_table(data: &[u8], count: usize) -> Vec<Vec<char>> {
+    let mut table = vec![Vec::new(); count];
+    let mut values = data.chunks_exact(2).map(|v| u16::from_le_bytes([v[0], v[1]]));
+    for chars in &mut table {
+        let mut in_sequence = false;
+        for value in values.by_ref() {
+            match value {
+                0xFFFF => break,
+                0xFFFE => in_sequence = true,
+                _ if !in_sequence => chars.extend(char::from_u32(value as u32)),
+                _ => {}
+            }
+        }
+    }
+    table
+}
+
+/// Characters of each glyph, entries are UTF-8 and end with 0xFF. Sequences after 0xFE are skipped
+fn psf2_table(data: &[u8], count: usize) -> Vec<Vec<char>> {
+    let mut table = vec![Vec::new(); count];
+    let mut entries = data.split(|b| *b == 0xFF);
+    for chars in &mut table {
+        let Some(entry) = entries.next() else { break };
+        let single = entry.split(|b| *b == 0xFE).next().unwrap_or_default();
+        chars.extend(String::from_utf8_lossy(single).chars().filter(|c| *c != char::REPLACEMENT_CHARACTER));
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// An A shape, 8 pixels wide
+    const GLYPH_A: [u8; 8] = [0x18, 0x24, 0x42, 0x42, 0x7E, 0x42, 0x42, 0x00];
+
+    fn psf1(mode: u8, table: &[u16]) -> Vec<u8> {
+        let count = if mode & 0x01 != 0 { 512 } else { 256 };
+        let mut bytes = vec![PSF1_MAGIC[0], PSF1_MAGIC[1], mode, 8];
+        for n in 0..count {
+            bytes.extend(if n == 'A' as usize { GLYPH_A } else { [n as u8; 8] });
+        }
+        bytes.extend(table.iter().flat_map(|v| v.to_le_bytes()));
+        bytes
+    }
+
+    /// Three 6x10 glyphs, the unicode table maps them to `a`, `b` & `c` and `é`
+    fn psf2() -> Vec<u8> {
+        let mut bytes = PSF2_MAGIC.to_vec();
+        for field in [0, 32, 1, 3, 10, 10, 6] {
+            bytes.extend(u32::to_le_bytes(field));
+        }
+        for n in 0..30u8 {
+            bytes.push(n.wrapping_mul(37) & 0xFC);
+        }
+        bytes.extend(b"a\xFFbc\xFExyz\xFF");
+        bytes.extend("é".as_bytes());
+        bytes.push(0xFF);
+        bytes
+    }
+
+    const BDF: &str = "STARTFONT 2.1
+FONT -test-fixed-medium-r-normal--10-100-75-75-c-60-iso10646-1
+SIZE 10 75 75
+FONTBOUNDINGBOX 6 10 0 -2
+STARTPROPERTIES 2
+FONT_ASCENT 8
+FONT_DESCENT 2
+ENDPROPERTIES
+CHARS 3
+STARTCHAR A
+ENCODING 65
+DWIDTH 6 0
+BBX 5 7 0 0
+BITMAP
+20
+50
+88
+88
+F8
+88
+88
+ENDCHAR
+STARTCHAR g
+ENCODING 103
+DWIDTH 6 0
+BBX 5 6 0 -2
+BITMAP
+78
+88
+88
+78
+08
+70
+ENDCHAR
+STARTCHAR unmapped
+ENCODING -1
+BBX 5 7 0 0
+BITMAP
+F8
+ENDCHAR
+ENDFONT
+";
+
+    #[test]
+    fn psf1_without_a_table() {
+        let font = BitmapFont::from_psf(&psf1(0, &[])).unwrap();
+        assert!(font.has_glyph('A') && font.has_glyph('\u{FF}'));
+        assert!(!font.has_glyph('\u{100}'));
+        assert_eq!((font.ascent(), font.line_height()), (8, 8));
+        assert_eq!(font.text_width("AB"), 16);
+
+        let mask = font.rasterize("A");
+        assert_eq!(mask.size, Vec2::new(8, 8));
+        let expected: Vec<u8> = GLYPH_A.iter().flat_map(|row| (0..8).map(move |x| (row >> (7 - x) & 1) * 255)).collect();
+        assert_eq!(mask.alpha, expected);
+
+        let font = BitmapFont::from_psf(&psf1(0x01, &[])).unwrap();
+        assert!(font.has_glyph('\u{1FF}'));
+    }
+
+    #[test]
+    fn psf1_with_a_table() {
+        // Glyph 0 is é, glyph 1 is A with a sequence that gets skipped, the rest are unmapped
+        let mut table = vec![0xE9, 0xFFFF, 0x41, 0xFFFE, 0x42, 0x43, 0xFFFF];
+        table.extend([0xFFFF; 254]);
+        let font = BitmapFont::from_psf(&psf1(0x02, &table)).unwrap();
+        assert!(font.has_glyph('é') && font.has_glyph('A'));
+        assert!(!font.has_glyph('B') && !font.has_glyph('\0'));
+
+        // A table that's cut short still loads the glyphs it has
+        let font = BitmapFont::from_psf(&psf1(0x02, &table[..3])).unwrap();
+        assert!(font.has_glyph('é') && font.has_glyph('A'));
+    }
+
+    #[test]
+    fn psf2_with_a_table() {
+        let font = BitmapFont::from_psf(&psf2()).unwrap();
+        for c in ['a', 'b', 'c', 'é'] {
+            assert!(font.has_glyph(c), "{}", c);
+        }
+        assert!(!font.has_glyph('x'));
+        assert_eq!(font.line_height(), 10);
+        assert_eq!(font.text_width("abé"), 18);
+        assert_eq!(font.text_width("?"), 6);
+    }
+
+    #[test]
+    fn bdf() {
+        let font = BitmapFont::from_bdf(BDF.as_bytes()).unwrap();
+        assert!(font.has_glyph('A') && font.has_glyph('g'));
+        assert_eq!((font.ascent(), font.line_height()), (8, 10));
+        assert_eq!(font.text_width("Ag?"), 18);
+
+        // The descender of g reaches below the baseline
+        let mask = font.rasterize("Ag");
+        assert_eq!(mask.offset, Vec2::new(0, 1));
+        assert_eq!(mask.size, Vec2::new(11, 9));
+        assert_eq!(&mask.alpha[..5], &[0, 0, 255, 0, 0]);
+    }
+
+    #[test]
+    fn non_uniform_scales() {
+        let font = BitmapFont::from_psf(&psf1(0, &[])).unwrap();
+        let mask = font.rasterize("A").scaled(2, 3);
+        assert_eq!((mask.offset, mask.size), (Vec2::new(0, 0), Vec2::new(16, 24)));
+        let rows: Vec<String> = mask
+            .alpha
+            .chunks(16)
+            .map(|row| row.iter().map(|a| if *a == 255 { '#' } else { '.' }).collect())
+            .collect();
+        assert_eq!(rows[0..3], ["......####......"; 3]);
+        assert_eq!(rows[3..6], ["....##....##...."; 3]);
+        assert_eq!(rows[12], "..############..");
+
+        // The offset from the top of the line scales too
+        let font = BitmapFont::from_bdf(BDF.as_bytes()).unwrap();
+        let plain = font.rasterize("Ab");
+        assert_ne!(plain.offset, Vec2::new(0, 0));
+        let scaled = font.rasterize("Ab").scaled(2, 3);
+        assert_eq!(scaled.offset, Vec2::new(plain.offset.x * 2, plain.offset.y * 3));
+        assert_eq!(scaled.size, Vec2::new(plain.size.x * 2, plain.size.y * 3));
+    }
+
+    #[test]
+    fn bad_magic() {
+        for bytes in [&b""[..], b"\x36", b"\x72\xb5\x4a", b"PSF2 font", b"\x00\x00\x00\x00"] {
+            assert!(BitmapFont::from_psf(bytes).is_err());
+        }
+        for text in ["", "STARTCHAR A", "FONT foo\nSTARTFONT 2.1"] {
+            assert!(BitmapFont::from_bdf(text.as_bytes()).is_err());
+        }
+        assert!(BitmapFont::from_bdf(b"STARTFONT 2.1\n\xFF\xFE").is_err());
+    }
+
+    #[test]
+    fn truncated_files() {
+        let psf1 = psf1(0, &[]);
+        let psf2 = psf2();
+        let table = 12;
+        // Everything up to the end of the glyph data is required
+        for bytes in [&psf1[..], &psf2[..psf2.len() - table]] {
+            for len in 0..bytes.len() {
+                assert!(BitmapFont::from_psf(&bytes[..len]).is_err(), "{} bytes", len);
+            }
+        }
+        // Cut off tables only lose characters
+        for len in psf2.len() - table..psf2.len() {
+            assert!(BitmapFont::from_psf(&psf2[..len]).is_ok(), "{} bytes", len);
+        }
+        // BDF fonts need their bounding box, anything cut off after it may load with fewer glyphs
+        let bounding_box = BDF.find("FONTBOUNDINGBOX").unwrap();
+        for len in 0..BDF.len() {
+            let result = BitmapFont::from_bdf(&BDF.as_bytes()[..len]);
+            if len <= bounding_box {
+                assert!(result.is_err(), "{} bytes", len);
+            }
+        }
+    }
+
+    #[test]
+    fn bad_headers() {
+        let psf2_with = |field: usize, value: u32| {
+            let mut bytes = psf2();
+            bytes[field * 4..field * 4 + 4].copy_from_slice(&value.to_le_bytes());
+            bytes
+        };
+        // Glyph count, glyph size, height and width
+        for (field, value) in [(4, u32::MAX), (4, 5), (5, u32::MAX), (5, 2), (6, 0), (6, u32::MAX), (7, 0), (7, u32::MAX), (2, u32::MAX)] {
+            assert!(BitmapFont::from_psf(&psf2_with(field, value)).is_err(), "field {} = {}", field, value);
+        }
+        // Huge counts mustn't overflow when the glyph data size is worked out
+        let mut huge = psf2_with(4, u32::MAX);
+        huge[20..24].copy_from_slice(&u32::MAX.to_le_bytes());
+        assert!(BitmapFont::from_psf(&huge).is_err());
+
+        let bdf_with = |from: &str, to: &str| BitmapFont::from_bdf(BDF.replacen(from, to, 1).as_bytes());
+        assert!(bdf_with("FONTBOUNDINGBOX 6 10 0 -2\n", "").is_err());
+        assert!(bdf_with("BBX 5 7 0 0", "BBX 2147483647 2147483647 0 0").is_err());
+        assert!(bdf_with("BBX 5 7 0 0", "BBX 5 7 0 2147483647").is_err());
+        assert!(bdf_with("FONT_ASCENT 8", "FONT_ASCENT -2147483648").is_err());
+        assert!(bdf_with("DWIDTH 6 0", "DWIDTH x 0").is_err());
+        assert!(bdf_with("BBX 5 7 0 0", "BBX 5 7").is_err());
+        assert!(bdf_with("\n20\n", "\nZZ\n").is_err());
+        assert!(bdf_with("\n20\n", "\né\n").is_err());
+    }
+}
@@ -0,0 +1,488 @@
+// Font Taken From : https://github.com/dhepper/font8x8
+// These are its Latin-1, Greek, box drawing, block element and Hiragana sets, the fallback glyph at the end is our own
+
+// Latin-1 Supplement U+00A0 - U+00FF
+pub const FONT8X8_LATIN: [[u8; 8]; 96] = [
+    [ 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],   // U+00A0 (nbsp)
+    [ 0x18, 0x18, 0x00, 0x18, 0x18, 0x18, 0x18, 0x00],   // U+00A1 (¡)
+    [ 0x18, 0x18, 0x7E, 0x03, 0x03, 0x7E, 0x18, 0x18],   // U+00A2 (¢)
+    [ 0x1C, 0x36, 0x26, 0x0F, 0x06, 0x67, 0x3F, 0x00],   // U+00A3 (£)
+    [ 0x00, 0x00, 0x63, 0x3E, 0x36, 0x3E, 0x63, 0x00],   // U+00A4 (¤)
+    [ 0x33, 0x33, 0x1E, 0x3F, 0x0C, 0x3F, 0x0C, 0x0C],   // U+00A5 (¥)
+    [ 0x18, 0x18, 0x18, 0x00, 0x18, 0x18, 0x18, 0x00],   // U+00A6 (¦)
+    [ 0x7C, 0xC6, 0x1C, 0x36, 0x36, 0x1C, 0x33, 0x1E],   // U+00A7 (§)
+    [ 0x33, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],   // U+00A8 (¨)
+    [ 0x3C, 0x42, 0x99, 0x85, 0x85, 0x99, 0x42, 0x3C],   // U+00A9 (©)
+    [ 0x3C, 0x36, 0x36, 0x7C, 0x00, 0x00, 0x00, 0x00],   // U+00AA (ª)
+    [ 0x00, 0xCC, 0x66, 0x33, 0x66, 0xCC, 0x00, 0x00],   // U+00AB («)
+    [ 0x00, 0x00, 0x00, 0x3F, 0x30, 0x30, 0x00, 0x00],   // U+00AC (¬)
+    [ 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],   // U+00AD (soft hyphen)
+    [ 0x3C, 0x42, 0x9D, 0xA5, 0x9D, 0xA5, 0x42, 0x3C],   // U+00AE (®)
+    [ 0x7E, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],   // U+00AF (¯)
+    [ 0x1C, 0x36, 0x36, 0x1C, 0x00, 0x00, 0x00, 0x00],   // U+00B0 (°)
+    [ 0x18, 0x18, 0x7E, 0x18, 0x18, 0x00, 0x7E, 0x00],   // U+00B1 (±)
+    [ 0x1C, 0x30, 0x18, 0x0C, 0x3C, 0x00, 0x00, 0x00],   // U+00B2 (²)
+    [ 0x1C, 0x30, 0x18, 0x30, 0x1C, 0x00, 0x00, 0x00],   // U+00B3 (³)
+    [ 0x18, 0x0C, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],   // U+00B4 (´)
+    [ 0x00, 0x00, 0x66, 0x66, 0x66, 0x3E, 0x06, 0x03],   // U+00B5 (µ)
+    [ 0xFE, 0xDB, 0xDB, 0xDE, 0xD8, 0xD8, 0xD8, 0x00],   // U+00B6 (¶)
+    [ 0x00, 0x00, 0x00, 0x18, 0x18, 0x00, 0x00, 0x00],   // U+00B7 (·)
+    [ 0x00, 0x00, 0x00, 0x00, 0x00, 0x18, 0x30, 0x1E],   // U+00B8 (¸)
+    [ 0x08, 0x0C, 0x08, 0x1C, 0x00, 0x00, 0x00, 0x00],   // U+00B9 (¹)
+    [ 0x1C, 0x36, 0x36, 0x1C, 0x00, 0x00, 0x00, 0x00],   // U+00BA (º)
+    [ 0x00, 0x33, 0x66, 0xCC, 0x66, 0x33, 0x00, 0x00],   // U+00BB (»)
+    [ 0xC3, 0x63, 0x33, 0xBD, 0xEC, 0xF6, 0xF3, 0x03],   // U+00BC (¼)
+    [ 0xC3, 0x63, 0x33, 0x7B, 0xCC, 0x66, 0x33, 0xF0],   // U+00BD (½)
+    [ 0x03, 0xC4, 0x63, 0xB4, 0xDB, 0xAC, 0xE6, 0x80],   // U+00BE (¾)
+    [ 0x0C, 0x00, 0x0C, 0x06, 0x03, 0x33, 0x1E, 0x00],   // U+00BF (¿)
+    [ 0x07, 0x00, 0x1C, 0x36, 0x63, 0x7F, 0x63, 0x00],   // U+00C0 (À)
+    [ 0x70, 0x00, 0x1C, 0x36, 0x63, 0x7F, 0x63, 0x00],   // U+00C1 (Á)
+    [ 0x1C, 0x36, 0x00, 0x3E, 0x63, 0x7F, 0x63, 0x00],   // U+00C2 (Â)
+    [ 0x6E, 0x3B, 0x00, 0x3E, 0x63, 0x7F, 0x63, 0x00],   // U+00C3 (Ã)
+    [ 0x63, 0x1C, 0x36, 0x63, 0x7F, 0x63, 0x63, 0x00],   // U+00C4 (Ä)
+    [ 0x0C, 0x0C, 0x00, 0x1E, 0x33, 0x3F, 0x33, 0x00],   // U+00C5 (Å)
+    [ 0x7C, 0x36, 0x33, 0x7F, 0x33, 0x33, 0x73, 0x00],   // U+00C6 (Æ)
+    [ 0x1E, 0x33, 0x03, 0x33, 0x1E, 0x18, 0x30, 0x1E],   // U+00C7 (Ç)
+    [ 0x07, 0x00, 0x3F, 0x06, 0x1E, 0x06, 0x3F, 0x00],   // U+00C8 (È)
+    [ 0x38, 0x00, 0x3F, 0x06, 0x1E, 0x06, 0x3F, 0x00],   // U+00C9 (É)
+    [ 0x0C, 0x12, 0x3F, 0x06, 0x1E, 0x06, 0x3F, 0x00],   // U+00CA (Ê)
+    [ 0x36, 0x00, 0x3F, 0x06, 0x1E, 0x06, 0x3F, 0x00],   // U+00CB (Ë)
+    [ 0x07, 0x00, 0x1E, 0x0C, 0x0C, 0x0C, 0x1E, 0x00],   // U+00CC (Ì)
+    [ 0x38, 0x00, 0x1E, 0x0C, 0x0C, 0x0C, 0x1E, 0x00],   // U+00CD (Í)
+    [ 0x0C, 0x12, 0x00, 0x1E, 0x0C, 0x0C, 0x1E, 0x00],   // U+00CE (Î)
+    [ 0x33, 0x00, 0x1E, 0x0C, 0x0C, 0x0C, 0x1E, 0x00],   // U+00CF (Ï)
+    [ 0x3F, 0x66, 0x6F, 0x6F, 0x66, 0x66, 0x3F, 0x00],   // U+00D0 (Ð)
+    [ 0x3F, 0x00, 0x33, 0x37, 0x3F, 0x3B, 0x33, 0x00],   // U+00D1 (Ñ)
+    [ 0x0E, 0x00, 0x18, 0x3C, 0x66, 0x3C, 0x18, 0x00],   // U+00D2 (Ò)
+    [ 0x70, 0x00, 0x18, 0x3C, 0x66, 0x3C, 0x18, 0x00],   // U+00D3 (Ó)
+    [ 0x3C, 0x66, 0x18, 0x3C, 0x66, 0x3C, 0x18, 0x00],   // U+00D4 (Ô)
+    [ 0x6E, 0x3B, 0x00, 0x3E, 0x63, 0x63, 0x3E, 0x00],   // U+00D5 (Õ)
+    [ 0xC3, 0x18, 0x3C, 0x66, 0x66, 0x3C, 0x18, 0x00],   // U+00D6 (Ö)
+    [ 0x00, 0x36, 0x1C, 0x08, 0x1C, 0x36, 0x00, 0x00],   // U+00D7 (×)
+    [ 0x5C, 0x36, 0x73, 0x7B, 0x6F, 0x36, 0x1D, 0x00],   // U+00D8 (Ø)
+    [ 0x0E, 0x00, 0x66, 0x66, 0x66, 0x66, 0x3C, 0x00],   // U+00D9 (Ù)
+    [ 0x70, 0x00, 0x66, 0x66, 0x66, 0x66, 0x3C, 0x00],   // U+00DA (Ú)
+    [ 0x3C, 0x66, 0x00, 0x66, 0x66, 0x66, 0x3C, 0x00],   // U+00DB (Û)
+    [ 0x33, 0x00, 0x33, 0x33, 0x33, 0x33, 0x1E, 0x00],   // U+00DC (Ü)
+    [ 0x70, 0x00, 0x66, 0x66, 0x3C, 0x18, 0x18, 0x00],   // U+00DD (Ý)
+    [ 0x0F, 0x06, 0x3E, 0x66, 0x66, 0x3E, 0x06, 0x0F],   // U+00DE (Þ)
+    [ 0x00, 0x1E, 0x33, 0x1F, 0x33, 0x1F, 0x03, 0x03],   // U+00DF (ß)
+    [ 0x07, 0x00, 0x1E, 0x30, 0x3E, 0x33, 0x7E, 0x00],   // U+00E0 (à)
+    [ 0x38, 0x00, 0x1E, 0x30, 0x3E, 0x33, 0x7E, 0x00],   // U+00E1 (á)
+    [ 0x7E, 0xC3, 0x3C, 0x60, 0x7C, 0x66, 0xFC, 0x00],   // U+00E2 (â)
+    [ 0x6E, 0x3B, 0x1E, 0x30, 0x3E, 0x33, 0x7E, 0x00],   // U+00E3 (ã)
+    [ 0x33, 0x00, 0x1E, 0x30, 0x3E, 0x33, 0x7E, 0x00],   // U+00E4 (ä)
+    [ 0x0C, 0x0C, 0x1E, 0x30, 0x3E, 0x33, 0x7E, 0x00],   // U+00E5 (å)
+    [ 0x00, 0x00, 0xFE, 0x30, 0xFE, 0x33, 0xFE, 0x00],   // U+00E6 (æ)
+    [ 0x00, 0x00, 0x1E, 0x03, 0x03, 0x1E, 0x30, 0x1C],   // U+00E7 (ç)
+    [ 0x07, 0x00, 0x1E, 0x33, 0x3F, 0x03, 0x1E, 0x00],   // U+00E8 (è)
+    [ 0x38, 0x00, 0x1E, 0x33, 0x3F, 0x03, 0x1E, 0x00],   // U+00E9 (é)
+    [ 0x7E, 0xC3, 0x3C, 0x66, 0x7E, 0x06, 0x3C, 0x00],   // U+00EA (ê)
+    [ 0x33, 0x00, 0x1E, 0x33, 0x3F, 0x03, 0x1E, 0x00],   // U+00EB (ë)
+    [ 0x07, 0x00, 0x0E, 0x0C, 0x0C, 0x0C, 0x1E, 0x00],   // U+00EC (ì)
+    [ 0x1C, 0x00, 0x0E, 0x0C, 0x0C, 0x0C, 0x1E, 0x00],   // U+00ED (í)
+    [ 0x3E, 0x63, 0x1C, 0x18, 0x18, 0x18, 0x3C, 0x00],   // U+00EE (î)
+    [ 0x33, 0x00, 0x0E, 0x0C, 0x0C, 0x0C, 0x1E, 0x00],   // U+00EF (ï)
+    [ 0x1B, 0x0E, 0x1B, 0x30, 0x3E, 0x33, 0x1E, 0x00],   // U+00F0 (ð)
+    [ 0x00, 0x1F, 0x00, 0x1F, 0x33, 0x33, 0x33, 0x00],   // U+00F1 (ñ)
+    [ 0x00, 0x07, 0x00, 0x1E, 0x33, 0x33, 0x1E, 0x00],   // U+00F2 (ò)
+    [ 0x00, 0x38, 0x00, 0x1E, 0x33, 0x33, 0x1E, 0x00],   // U+00F3 (ó)
+    [ 0x1E, 0x33, 0x00, 0x1E, 0x33, 0x33, 0x1E, 0x00],   // U+00F4 (ô)
+    [ 0x6E, 0x3B, 0x00, 0x1E, 0x33, 0x33, 0x1E, 0x00],   // U+00F5 (õ)
+    [ 0x00, 0x33, 0x00, 0x1E, 0x33, 0x33, 0x1E, 0x00],   // U+00F6 (ö)
+    [ 0x18, 0x18, 0x00, 0x7E, 0x00, 0x18, 0x18, 0x00],   // U+00F7 (÷)
+    [ 0x00, 0x60, 0x3C, 0x76, 0x7E, 0x6E, 0x3C, 0x06],   // U+00F8 (ø)
+    [ 0x00, 0x07, 0x00, 0x33, 0x33, 0x33, 0x7E, 0x00],   // U+00F9 (ù)
+    [ 0x00, 0x38, 0x00, 0x33, 0x33, 0x33, 0x7E, 0x00],   // U+00FA (ú)
+    [ 0x1E, 0x33, 0x00, 0x33, 0x33, 0x33, 0x7E, 0x00],   // U+00FB (û)
+    [ 0x00, 0x33, 0x00, 0x33, 0x33, 0x33, 0x7E, 0x00],   // U+00FC (ü)
+    [ 0x00, 0x38, 0x00, 0x33, 0x33, 0x3E, 0x30, 0x1F],   // U+00FD (ý)
+    [ 0x00, 0x00, 0x06, 0x3E, 0x66, 0x3E, 0x06, 0x00],   // U+00FE (þ)
+    [ 0x00, 0x33, 0x00, 0x33, 0x33, 0x3E, 0x30, 0x1F]    // U+00FF (ÿ)
+];
+// Greek U+0390 - U+03C9, U+03A2 is unassigned
+pub const FONT8X8_GREEK: [[u8; 8]; 58] = [
+    [ 0x2D, 0x00, 0x0C, 0x0C, 0x0C, 0x2C, 0x18, 0x00],   // U+0390 (ΐ)
+    [ 0x0C, 0x1E, 0x33, 0x33, 0x3F, 0x33, 0x33, 0x00],   // U+0391 (Α)
+    [ 0x3F, 0x66, 0x66, 0x3E, 0x66, 0x66, 0x3F, 0x00],   // U+0392 (Β)
+    [ 0x3F, 0x33, 0x03, 0x03, 0x03, 0x03, 0x03, 0x00],   // U+0393 (Γ)
+    [ 0x08, 0x1C, 0x1C, 0x36, 0x36, 0x63, 0x7F, 0x00],   // U+0394 (Δ)
+    [ 0x7F, 0x46, 0x16, 0x1E, 0x16, 0x46, 0x7F, 0x00],   // U+0395 (Ε)
+    [ 0x7F, 0x63, 0x31, 0x18, 0x4C, 0x66, 0x7F, 0x00],   // U+0396 (Ζ)
+    [ 0x33, 0x33, 0x33, 0x3F, 0x33, 0x33, 0x33, 0x00],   // U+0397 (Η)
+    [ 0x1C, 0x36, 0x63, 0x7F, 0x63, 0x36, 0x1C, 0x00],   // U+0398 (Θ)
+    [ 0x1E, 0x0C, 0x0C, 0x0C, 0x0C, 0x0C, 0x1E, 0x00],   // U+0399 (Ι)
+    [ 0x67, 0x66, 0x36, 0x1E, 0x36, 0x66, 0x67, 0x00],   // U+039A (Κ)
+    [ 0x08, 0x1C, 0x1C, 0x36, 0x36, 0x63, 0x63, 0x00],   // U+039B (Λ)
+    [ 0x63, 0x77, 0x7F, 0x7F, 0x6B, 0x63, 0x63, 0x00],   // U+039C (Μ)
+    [ 0x63, 0x67, 0x6F, 0x7B, 0x73, 0x63, 0x63, 0x00],   // U+039D (Ν)
+    [ 0x7F, 0x63, 0x00, 0x3E, 0x00, 0x63, 0x7F, 0x00],   // U+039E (Ξ)
+    [ 0x1C, 0x36, 0x63, 0x63, 0x63, 0x36, 0x1C, 0x00],   // U+039F (Ο)
+    [ 0x7F, 0x36, 0x36, 0x36, 0x36, 0x36, 0x36, 0x00],   // U+03A0 (Π)
+    [ 0x3F, 0x66, 0x66, 0x3E, 0x06, 0x06, 0x0F, 0x00],   // U+03A1 (Ρ)
+    [ 0x00, 0x01, 0x02, 0x04, 0x4F, 0x90, 0xA0, 0x40],   // U+03A2
+    [ 0x7F, 0x63, 0x06, 0x0C, 0x06, 0x63, 0x7F, 0x00],   // U+03A3 (Σ)
+    [ 0x3F, 0x2D, 0x0C, 0x0C, 0x0C, 0x0C, 0x1E, 0x00],   // U+03A4 (Τ)
+    [ 0x33, 0x33, 0x33, 0x1E, 0x0C, 0x0C, 0x1E, 0x00],   // U+03A5 (Υ)
+    [ 0x18, 0x7E, 0xDB, 0xDB, 0xDB, 0x7E, 0x18, 0x00],   // U+03A6 (Φ)
+    [ 0x63, 0x63, 0x36, 0x1C, 0x36, 0x63, 0x63, 0x00],   // U+03A7 (Χ)
+    [ 0xDB, 0xDB, 0xDB, 0x7E, 0x18, 0x18, 0x3C, 0x00],   // U+03A8 (Ψ)
+    [ 0x3E, 0x63, 0x63, 0x63, 0x36, 0x36, 0x77, 0x00],   // U+03A9 (Ω)
+    [ 0x33, 0x00, 0x1E, 0x0C, 0x0C, 0x0C, 0x1E, 0x00],   // U+03AA (Ϊ)
+    [ 0x33, 0x00, 0x33, 0x33, 0x1E, 0x0C, 0x1E, 0x00],   // U+03AB (Ϋ)
+    [ 0x70, 0x00, 0x6E, 0x3B, 0x13, 0x3B, 0x6E, 0x00],   // U+03AC (ά)
+    [ 0x38, 0x00, 0x1E, 0x03, 0x0E, 0x03, 0x1E, 0x00],   // U+03AD (έ)
+    [ 0x38, 0x00, 0x1F, 0x33, 0x33, 0x33, 0x33, 0x30],   // U+03AE (ή)
+    [ 0x38, 0x00, 0x0C, 0x0C, 0x0C, 0x2C, 0x18, 0x00],   // U+03AF (ί)
+    [ 0x2D, 0x00, 0x33, 0x33, 0x33, 0x33, 0x1E, 0x00],   // U+03B0 (ΰ)
+    [ 0x00, 0x00, 0x6E, 0x3B, 0x13, 0x3B, 0x6E, 0x00],   // U+03B1 (α)
+    [ 0x00, 0x1E, 0x33, 0x1F, 0x33, 0x1F, 0x03, 0x03],   // U+03B2 (β)
+    [ 0x00, 0x00, 0x33, 0x33, 0x1E, 0x0C, 0x0C, 0x00],   // U+03B3 (γ)
+    [ 0x38, 0x0C, 0x18, 0x3E, 0x33, 0x33, 0x1E, 0x00],   // U+03B4 (δ)
+    [ 0x00, 0x00, 0x1E, 0x03, 0x0E, 0x03, 0x1E, 0x00],   // U+03B5 (ε)
+    [ 0x00, 0x3F, 0x06, 0x03, 0x03, 0x1E, 0x30, 0x1C],   // U+03B6 (ζ)
+    [ 0x00, 0x00, 0x1F, 0x33, 0x33, 0x33, 0x33, 0x30],   // U+03B7 (η)
+    [ 0x00, 0x00, 0x1E, 0x33, 0x3F, 0x33, 0x1E, 0x00],   // U+03B8 (θ)
+    [ 0x00, 0x00, 0x0C, 0x0C, 0x0C, 0x2C, 0x18, 0x00],   // U+03B9 (ι)
+    [ 0x00, 0x00, 0x33, 0x1B, 0x0F, 0x1B, 0x33, 0x00],   // U+03BA (κ)
+    [ 0x00, 0x03, 0x06, 0x0C, 0x1C, 0x36, 0x63, 0x00],   // U+03BB (λ)
+    [ 0x00, 0x00, 0x66, 0x66, 0x66, 0x3E, 0x06, 0x03],   // U+03BC (μ)
+    [ 0x00, 0x00, 0x33, 0x33, 0x33, 0x1E, 0x0C, 0x00],   // U+03BD (ν)
+    [ 0x1E, 0x03, 0x0E, 0x03, 0x03, 0x1E, 0x30, 0x1C],   // U+03BE (ξ)
+    [ 0x00, 0x00, 0x1E, 0x33, 0x33, 0x33, 0x1E, 0x00],   // U+03BF (ο)
+    [ 0x00, 0x00, 0x7F, 0x36, 0x36, 0x36, 0x36, 0x00],   // U+03C0 (π)
+    [ 0x00, 0x00, 0x3C, 0x66, 0x66, 0x36, 0x06, 0x06],   // U+03C1 (ρ)
+    [ 0x00, 0x00, 0x3E, 0x03, 0x03, 0x1E, 0x30, 0x1C],   // U+03C2 (ς)
+    [ 0x00, 0x00, 0x7E, 0x1B, 0x1B, 0x1B, 0x0E, 0x00],   // U+03C3 (σ)
+    [ 0x00, 0x00, 0x7E, 0x18, 0x18, 0x58, 0x30, 0x00],   // U+03C4 (τ)
+    [ 0x00, 0x00, 0x33, 0x33, 0x33, 0x33, 0x1E, 0x00],   // U+03C5 (υ)
+    [ 0x00, 0x00, 0x76, 0xDB, 0xDB, 0x7E, 0x18, 0x00],   // U+03C6 (φ)
+    [ 0x00, 0x63, 0x36, 0x1C, 0x1C, 0x36, 0x63, 0x00],   // U+03C7 (χ)
+    [ 0x00, 0x00, 0xDB, 0xDB, 0xDB, 0x7E, 0x18, 0x00],   // U+03C8 (ψ)
+    [ 0x00, 0x00, 0x36, 0x63, 0x6B, 0x7F, 0x36, 0x00]    // U+03C9 (ω)
+];
+// Box Drawing U+2500 - U+257F
+pub const FONT8X8_BOX: [[u8; 8]; 128] = [
+    [ 0x00, 0x00, 0x00, 0x00, 0xFF, 0x00, 0x00, 0x00],   // U+2500 (─)
+    [ 0x00, 0x00, 0x00, 0xFF, 0xFF, 0x00, 0x00, 0x00],   // U+2501 (━)
+    [ 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08],   // U+2502 (│)
+    [ 0x18, 0x18, 0x18, 0x18, 0x18, 0x18, 0x18, 0x18],   // U+2503 (┃)
+    [ 0x00, 0x00, 0x00, 0x00, 0xBB, 0x00, 0x00, 0x00],   // U+2504 (┄)
+    [ 0x00, 0x00, 0x00, 0xBB, 0xBB, 0x00, 0x00, 0x00],   // U+2505 (┅)
+    [ 0x08, 0x00, 0x08, 0x08, 0x08, 0x00, 0x08, 0x08],   // U+2506 (┆)
+    [ 0x18, 0x00, 0x18, 0x18, 0x18, 0x00, 0x18, 0x18],   // U+2507 (┇)
+    [ 0x00, 0x00, 0x00, 0x00, 0x55, 0x00, 0x00, 0x00],   // U+2508 (┈)
+    [ 0x00, 0x00, 0x00, 0x55, 0x55, 0x00, 0x00, 0x00],   // U+2509 (┉)
+    [ 0x00, 0x08, 0x00, 0x08, 0x00, 0x08, 0x00, 0x08],   // U+250A (┊)
+    [ 0x00, 0x18, 0x00, 0x18, 0x00, 0x18, 0x00, 0x18],   // U+250B (┋)
+    [ 0x00, 0x00, 0x00, 0x00, 0xF8, 0x08, 0x08, 0x08],   // U+250C (┌)
+    [ 0x00, 0x00, 0x00, 0xF8, 0xF8, 0x08, 0x08, 0x08],   // U+250D (┍)
+    [ 0x00, 0x00, 0x00, 0x00, 0xF8, 0x18, 0x18, 0x18],   // U+250E (┎)
+    [ 0x00, 0x00, 0x00, 0xF8, 0xF8, 0x18, 0x18, 0x18],   // U+250F (┏)
+    [ 0x00, 0x00, 0x00, 0x00, 0x0F, 0x08, 0x08, 0x08],   // U+2510 (┐)
+    [ 0x00, 0x00, 0x00, 0x0F, 0x0F, 0x08, 0x08, 0x08],   // U+2511 (┑)
+    [ 0x00, 0x00, 0x00, 0x00, 0x1F, 0x18, 0x18, 0x18],   // U+2512 (┒)
+    [ 0x00, 0x00, 0x00, 0x1F, 0x1F, 0x18, 0x18, 0x18],   // U+2513 (┓)
+    [ 0x08, 0x08, 0x08, 0x08, 0xF8, 0x00, 0x00, 0x00],   // U+2514 (└)
+    [ 0x08, 0x08, 0x08, 0xF8, 0xF8, 0x00, 0x00, 0x00],   // U+2515 (┕)
+    [ 0x18, 0x18, 0x18, 0x18, 0xF8, 0x00, 0x00, 0x00],   // U+2516 (┖)
+    [ 0x18, 0x18, 0x18, 0xF8, 0xF8, 0x00, 0x00, 0x00],   // U+2517 (┗)
+    [ 0x08, 0x08, 0x08, 0x08, 0x0F, 0x00, 0x00, 0x00],   // U+2518 (┘)
+    [ 0x08, 0x08, 0x08, 0x0F, 0x0F, 0x00, 0x00, 0x00],   // U+2519 (┙)
+    [ 0x18, 0x18, 0x18, 0x18, 0x1F, 0x00, 0x00, 0x00],   // U+251A (┚)
+    [ 0x18, 0x18, 0x18, 0x1F, 0x1F, 0x00, 0x00, 0x00],   // U+251B (┛)
+    [ 0x08, 0x08, 0x08, 0x08, 0xF8, 0x08, 0x08, 0x08],   // U+251C (├)
+    [ 0x08, 0x08, 0x08, 0xF8, 0xF8, 0x08, 0x08, 0x08],   // U+251D (┝)
+    [ 0x18, 0x18, 0x18, 0x18, 0xF8, 0x08, 0x08, 0x08],   // U+251E (┞)
+    [ 0x08, 0x08, 0x08, 0x08, 0xF8, 0x18, 0x18, 0x18],   // U+251F (┟)
+    [ 0x18, 0x18, 0x18, 0x18, 0xF8, 0x18, 0x18, 0x18],   // U+2520 (┠)
+    [ 0x18, 0x18, 0x18, 0xF8, 0xF8, 0x08, 0x08, 0x08],   // U+2521 (┡)
+    [ 0x08, 0x08, 0x08, 0xF8, 0xF8, 0x18, 0x18, 0x18],   // U+2522 (┢)
+    [ 0x18, 0x18, 0x18, 0xF8, 0xF8, 0x18, 0x18, 0x18],   // U+2523 (┣)
+    [ 0x08, 0x08, 0x08, 0x08, 0x0F, 0x08, 0x08, 0x08],   // U+2524 (┤)
+    [ 0x08, 0x08, 0x08, 0x0F, 0x0F, 0x08, 0x08, 0x08],   // U+2525 (┥)
+    [ 0x18, 0x18, 0x18, 0x18, 0x1F, 0x08, 0x08, 0x08],   // U+2526 (┦)
+    [ 0x08, 0x08, 0x08, 0x08, 0x1F, 0x18, 0x18, 0x18],   // U+2527 (┧)
+    [ 0x18, 0x18, 0x18, 0x18, 0x1F, 0x18, 0x18, 0x18],   // U+2528 (┨)
+    [ 0x18, 0x18, 0x18, 0x1F, 0x1F, 0x08, 0x08, 0x08],   // U+2529 (┩)
+    [ 0x08, 0x08, 0x08, 0x1F, 0x1F, 0x18, 0x18, 0x18],   // U+252A (┪)
+    [ 0x18, 0x18, 0x18, 0x1F, 0x1F, 0x18, 0x18, 0x18],   // U+252B (┫)
+    [ 0x00, 0x00, 0x00, 0x00, 0xFF, 0x08, 0x08, 0x08],   // U+252C (┬)
+    [ 0x00, 0x00, 0x00, 0x0F, 0xFF, 0x08, 0x08, 0x08],   // U+252D (┭)
+    [ 0x00, 0x00, 0x00, 0xF8, 0xFF, 0x08, 0x08, 0x08],   // U+252E (┮)
+    [ 0x00, 0x00, 0x00, 0xFF, 0xFF, 0x08, 0x08, 0x08],   // U+252F (┯)
+    [ 0x00, 0x00, 0x00, 0x00, 0xFF, 0x18, 0x18, 0x18],   // U+2530 (┰)
+    [ 0x00, 0x00, 0x00, 0x1F, 0xFF, 0x18, 0x18, 0x18],   // U+2531 (┱)
+    [ 0x00, 0x00, 0x00, 0xF8, 0xFF, 0x18, 0x18, 0x18],   // U+2532 (┲)
+    [ 0x00, 0x00, 0x00, 0xFF, 0xFF, 0x18, 0x18, 0x18],   // U+2533 (┳)
+    [ 0x08, 0x08, 0x08, 0x08, 0xFF, 0x00, 0x00, 0x00],   // U+2534 (┴)
+    [ 0x08, 0x08, 0x08, 0x0F, 0xFF, 0x00, 0x00, 0x00],   // U+2535 (┵)
+    [ 0x08, 0x08, 0x08, 0xF8, 0xFF, 0x00, 0x00, 0x00],   // U+2536 (┶)
+    [ 0x08, 0x08, 0x08, 0xFF, 0xFF, 0x00, 0x00, 0x00],   // U+2537 (┷)
+    [ 0x18, 0x18, 0x18, 0x18, 0xFF, 0x00, 0x00, 0x00],   // U+2538 (┸)
+    [ 0x18, 0x18, 0x18, 0x1F, 0xFF, 0x00, 0x00, 0x00],   // U+2539 (┹)
+    [ 0x18, 0x18, 0x18, 0xF8, 0xFF, 0x00, 0x00, 0x00],   // U+253A (┺)
+    [ 0x18, 0x18, 0x18, 0xFF, 0xFF, 0x00, 0x00, 0x00],   // U+253B (┻)
+    [ 0x08, 0x08, 0x08, 0x08, 0xFF, 0x08, 0x08, 0x08],   // U+253C (┼)
+    [ 0x08, 0x08, 0x08, 0x0F, 0xFF, 0x08, 0x08, 0x08],   // U+253D (┽)
+    [ 0x08, 0x08, 0x08, 0xF8, 0xFF, 0x08, 0x08, 0x08],   // U+253E (┾)
+    [ 0x08, 0x08, 0x08, 0xFF, 0xFF, 0x08, 0x08, 0x08],   // U+253F (┿)
+    [ 0x18, 0x18, 0x18, 0x18, 0xFF, 0x08, 0x08, 0x08],   // U+2540 (╀)
+    [ 0x08, 0x08, 0x08, 0x08, 0xFF, 0x18, 0x18, 0x18],   // U+2541 (╁)
+    [ 0x18, 0x18, 0x18, 0x18, 0xFF, 0x18, 0x18, 0x18],   // U+2542 (╂)
+    [ 0x18, 0x18, 0x18, 0x1F, 0xFF, 0x08, 0x08, 0x08],   // U+2543 (╃)
+    [ 0x18, 0x18, 0x18, 0xF8, 0xFF, 0x08, 0x08, 0x08],   // U+2544 (╄)
+    [ 0x08, 0x08, 0x08, 0x1F, 0xFF, 0x18, 0x18, 0x18],   // U+2545 (╅)
+    [ 0x08, 0x08, 0x08, 0xF8, 0xFF, 0x18, 0x18, 0x18],   // U+2546 (╆)
+    [ 0x08, 0x08, 0x08, 0xFF, 0xFF, 0x18, 0x18, 0x18],   // U+2547 (╇)
+    [ 0x18, 0x18, 0x18, 0xFF, 0xFF, 0x08, 0x08, 0x08],   // U+2548 (╈)
+    [ 0x18, 0x18, 0x18, 0xF8, 0xFF, 0x18, 0x18, 0x18],   // U+2549 (╉)
+    [ 0x18, 0x18, 0x18, 0x1F, 0xFF, 0x18, 0x18, 0x18],   // U+254A (╊)
+    [ 0x18, 0x18, 0x18, 0xFF, 0xFF, 0x18, 0x18, 0x18],   // U+254B (╋)
+    [ 0x00, 0x00, 0x00, 0x00, 0xE7, 0x00, 0x00, 0x00],   // U+254C (╌)
+    [ 0x00, 0x00, 0x00, 0xE7, 0xE7, 0x00, 0x00, 0x00],   // U+254D (╍)
+    [ 0x08, 0x08, 0x08, 0x00, 0x00, 0x08, 0x08, 0x08],   // U+254E (╎)
+    [ 0x18, 0x18, 0x18, 0x00, 0x00, 0x18, 0x18, 0x18],   // U+254F (╏)
+    [ 0x00, 0x00, 0x00, 0xFF, 0x00, 0xFF, 0x00, 0x00],   // U+2550 (═)
+    [ 0x14, 0x14, 0x14, 0x14, 0x14, 0x14, 0x14, 0x14],   // U+2551 (║)
+    [ 0x00, 0x00, 0x00, 0xF8, 0x08, 0xF8, 0x08, 0x08],   // U+2552 (╒)
+    [ 0x00, 0x00, 0x00, 0x00, 0xFC, 0x14, 0x14, 0x14],   // U+2553 (╓)
+    [ 0x00, 0x00, 0x00, 0xFC, 0x04, 0xF4, 0x14, 0x14],   // U+2554 (╔)
+    [ 0x00, 0x00, 0x00, 0x0F, 0x08, 0x0F, 0x08, 0x08],   // U+2555 (╕)
+    [ 0x00, 0x00, 0x00, 0x00, 0x1F, 0x14, 0x14, 0x14],   // U+2556 (╖)
+    [ 0x00, 0x00, 0x00, 0x1F, 0x10, 0x17, 0x14, 0x14],   // U+2557 (╗)
+    [ 0x08, 0x08, 0x08, 0xF8, 0x08, 0xF8, 0x00, 0x00],   // U+2558 (╘)
+    [ 0x14, 0x14, 0x14, 0x14, 0xFC, 0x00, 0x00, 0x00],   // U+2559 (╙)
+    [ 0x14, 0x14, 0x14, 0xF4, 0x04, 0xFC, 0x00, 0x00],   // U+255A (╚)
+    [ 0x08, 0x08, 0x08, 0x0F, 0x08, 0x0F, 0x00, 0x00],   // U+255B (╛)
+    [ 0x14, 0x14, 0x14, 0x14, 0x1F, 0x00, 0x00, 0x00],   // U+255C (╜)
+    [ 0x14, 0x14, 0x14, 0x17, 0x10, 0x1F, 0x00, 0x00],   // U+255D (╝)
+    [ 0x08, 0x08, 0x08, 0xF8, 0x08, 0xF8, 0x08, 0x08],   // U+255E (╞)
+    [ 0x14, 0x14, 0x14, 0x14, 0xF4, 0x14, 0x14, 0x14],   // U+255F (╟)
+    [ 0x14, 0x14, 0x14, 0xF4, 0x04, 0xF4, 0x14, 0x14],   // U+2560 (╠)
+    [ 0x08, 0x08, 0x08, 0x0F, 0x08, 0x0F, 0x08, 0x08],   // U+2561 (╡)
+    [ 0x14, 0x14, 0x14, 0x14, 0x17, 0x14, 0x14, 0x14],   // U+2562 (╢)
+    [ 0x14, 0x14, 0x14, 0x17, 0x10, 0x17, 0x14, 0x14],   // U+2563 (╣)
+    [ 0x00, 0x00, 0x00, 0xFF, 0x00, 0xFF, 0x08, 0x08],   // U+2564 (╤)
+    [ 0x00, 0x00, 0x00, 0x00, 0xFF, 0x14, 0x14, 0x14],   // U+2565 (╥)
+    [ 0x00, 0x00, 0x00, 0xFF, 0x00, 0xF7, 0x14, 0x14],   // U+2566 (╦)
+    [ 0x08, 0x08, 0x08, 0xFF, 0x00, 0xFF, 0x00, 0x00],   // U+2567 (╧)
+    [ 0x14, 0x14, 0x14, 0x14, 0xFF, 0x00, 0x00, 0x00],   // U+2568 (╨)
+    [ 0x14, 0x14, 0x14, 0xF7, 0x00, 0xFF, 0x00, 0x00],   // U+2569 (╩)
+    [ 0x08, 0x08, 0x08, 0xFF, 0x08, 0xFF, 0x08, 0x08],   // U+256A (╪)
+    [ 0x14, 0x14, 0x14, 0x14, 0xFF, 0x14, 0x14, 0x14],   // U+256B (╫)
+    [ 0x14, 0x14, 0x14, 0xF7, 0x00, 0xF7, 0x14, 0x14],   // U+256C (╬)
+    [ 0x00, 0x00, 0x00, 0x00, 0xE0, 0x10, 0x08, 0x08],   // U+256D (╭)
+    [ 0x00, 0x00, 0x00, 0x00, 0x03, 0x04, 0x08, 0x08],   // U+256E (╮)
+    [ 0x08, 0x08, 0x08, 0x04, 0x03, 0x00, 0x00, 0x00],   // U+256F (╯)
+    [ 0x08, 0x08, 0x08, 0x10, 0xE0, 0x00, 0x00, 0x00],   // U+2570 (╰)
+    [ 0x80, 0x40, 0x20, 0x10, 0x08, 0x04, 0x02, 0x01],   // U+2571 (╱)
+    [ 0x01, 0x02, 0x04, 0x08, 0x10, 0x20, 0x40, 0x80],   // U+2572 (╲)
+    [ 0x81, 0x42, 0x24, 0x18, 0x18, 0x24, 0x42, 0x81],   // U+2573 (╳)
+    [ 0x00, 0x00, 0x00, 0x00, 0x0F, 0x00, 0x00, 0x00],   // U+2574 (╴)
+    [ 0x08, 0x08, 0x08, 0x08, 0x00, 0x00, 0x00, 0x00],   // U+2575 (╵)
+    [ 0x00, 0x00, 0x00, 0x00, 0xF8, 0x00, 0x00, 0x00],   // U+2576 (╶)
+    [ 0x00, 0x00, 0x00, 0x00, 0x08, 0x08, 0x08, 0x08],   // U+2577 (╷)
+    [ 0x00, 0x00, 0x00, 0x0F, 0x0F, 0x00, 0x00, 0x00],   // U+2578 (╸)
+    [ 0x18, 0x18, 0x18, 0x18, 0x00, 0x00, 0x00, 0x00],   // U+2579 (╹)
+    [ 0x00, 0x00, 0x00, 0xF8, 0xF8, 0x00, 0x00, 0x00],   // U+257A (╺)
+    [ 0x00, 0x00, 0x00, 0x00, 0x18, 0x18, 0x18, 0x18],   // U+257B (╻)
+    [ 0x00, 0x00, 0x00, 0xF8, 0xFF, 0x00, 0x00, 0x00],   // U+257C (╼)
+    [ 0x08, 0x08, 0x08, 0x08, 0x18, 0x18, 0x18, 0x18],   // U+257D (╽)
+    [ 0x00, 0x00, 0x00, 0x0F, 0xFF, 0x00, 0x00, 0x00],   // U+257E (╾)
+    [ 0x18, 0x18, 0x18, 0x18, 0x08, 0x08, 0x08, 0x08]    // U+257F (╿)
+];
+// Block Elements U+2580 - U+259F
+pub const FONT8X8_BLOCK: [[u8; 8]; 32] = [
+    [ 0xFF, 0xFF, 0xFF, 0xFF, 0x00, 0x00, 0x00, 0x00],   // U+2580 (▀)
+    [ 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xFF],   // U+2581 (▁)
+    [ 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xFF, 0xFF],   // U+2582 (▂)
+    [ 0x00, 0x00, 0x00, 0x00, 0x00, 0xFF, 0xFF, 0xFF],   // U+2583 (▃)
+    [ 0x00, 0x00, 0x00, 0x00, 0xFF, 0xFF, 0xFF, 0xFF],   // U+2584 (▄)
+    [ 0x00, 0x00, 0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF],   // U+2585 (▅)
+    [ 0x00, 0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF],   // U+2586 (▆)
+    [ 0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF],   // U+2587 (▇)
+    [ 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF],   // U+2588 (█)
+    [ 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F],   // U+2589 (▉)
+    [ 0x3F, 0x3F, 0x3F, 0x3F, 0x3F, 0x3F, 0x3F, 0x3F],   // U+258A (▊)
+    [ 0x1F, 0x1F, 0x1F, 0x1F, 0x1F, 0x1F, 0x1F, 0x1F],   // U+258B (▋)
+    [ 0x0F, 0x0F, 0x0F, 0x0F, 0x0F, 0x0F, 0x0F, 0x0F],   // U+258C (▌)
+    [ 0x07, 0x07, 0x07, 0x07, 0x07, 0x07, 0x07, 0x07],   // U+258D (▍)
+    [ 0x03, 0x03, 0x03, 0x03, 0x03, 0x03, 0x03, 0x03],   // U+258E (▎)
+    [ 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01],   // U+258F (▏)
+    [ 0xF0, 0xF0, 0xF0, 0xF0, 0xF0, 0xF0, 0xF0, 0xF0],   // U+2590 (▐)
+    [ 0x55, 0x00, 0xAA, 0x00, 0x55, 0x00, 0xAA, 0x00],   // U+2591 (░)
+    [ 0x55, 0xAA, 0x55, 0xAA, 0x55, 0xAA, 0x55, 0xAA],   // U+2592 (▒)
+    [ 0xFF, 0xAA, 0xFF, 0x55, 0xFF, 0xAA, 0xFF, 0x55],   // U+2593 (▓)
+    [ 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],   // U+2594 (▔)
+    [ 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80],   // U+2595 (▕)
+    [ 0x00, 0x00, 0x00, 0x00, 0x0F, 0x0F, 0x0F, 0x0F],   // U+2596 (▖)
+    [ 0x00, 0x00, 0x00, 0x00, 0xF0, 0xF0, 0xF0, 0xF0],   // U+2597 (▗)
+    [ 0x0F, 0x0F, 0x0F, 0x0F, 0x00, 0x00, 0x00, 0x00],   // U+2598 (▘)
+    [ 0x0F, 0x0F, 0x0F, 0x0F, 0xFF, 0xFF, 0xFF, 0xFF],   // U+2599 (▙)
+    [ 0x0F, 0x0F, 0x0F, 0x0F, 0xF0, 0xF0, 0xF0, 0xF0],   // U+259A (▚)
+    [ 0xFF, 0xFF, 0xFF, 0xFF, 0x0F, 0x0F, 0x0F, 0x0F],   // U+259B (▛)
+    [ 0xFF, 0xFF, 0xFF, 0xFF, 0xF0, 0xF0, 0xF0, 0xF0],   // U+259C (▜)
+    [ 0xF0, 0xF0, 0xF0, 0xF0, 0x00, 0x00, 0x00, 0x00],   // U+259D (▝)
+    [ 0xF0, 0xF0, 0xF0, 0xF0, 0x0F, 0x0F, 0x0F, 0x0F],   // U+259E (▞)
+    [ 0xF0, 0xF0, 0xF0, 0xF0, 0xFF, 0xFF, 0xFF, 0xFF]    // U+259F (▟)
+];
+// Hiragana U+3040 - U+309F, U+3040, U+3097 and U+3098 are unassigned and the set has nothing for U+3095, U+3096 and U+309F
+pub const FONT8X8_HIRAGANA: [[u8; 8]; 96] = [
+    [ 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],   // U+3040
+    [ 0x04, 0x3F, 0x04, 0x3C, 0x56, 0x4D, 0x26, 0x00],   // U+3041 (ぁ)
+    [ 0x04, 0x3F, 0x04, 0x3C, 0x56, 0x4D, 0x26, 0x00],   // U+3042 (あ)
+    [ 0x00, 0x00, 0x00, 0x11, 0x21, 0x25, 0x02, 0x00],   // U+3043 (ぃ)
+    [ 0x00, 0x01, 0x11, 0x21, 0x21, 0x25, 0x02, 0x00],   // U+3044 (い)
+    [ 0x00, 0x1C, 0x00, 0x1C, 0x22, 0x20, 0x18, 0x00],   // U+3045 (ぅ)
+    [ 0x3C, 0x00, 0x3C, 0x42, 0x40, 0x20, 0x18, 0x00],   // U+3046 (う)
+    [ 0x1C, 0x00, 0x3E, 0x10, 0x38, 0x24, 0x62, 0x00],   // U+3047 (ぇ)
+    [ 0x1C, 0x00, 0x3E, 0x10, 0x38, 0x24, 0x62, 0x00],   // U+3048 (え)
+    [ 0x24, 0x4F, 0x04, 0x3C, 0x46, 0x45, 0x22, 0x00],   // U+3049 (ぉ)
+    [ 0x24, 0x4F, 0x04, 0x3C, 0x46, 0x45, 0x22, 0x00],   // U+304A (お)
+    [ 0x04, 0x24, 0x4F, 0x54, 0x52, 0x12, 0x09, 0x00],   // U+304B (か)
+    [ 0x44, 0x24, 0x0F, 0x54, 0x52, 0x52, 0x09, 0x00],   // U+304C (が)
+    [ 0x08, 0x1F, 0x08, 0x3F, 0x1C, 0x02, 0x3C, 0x00],   // U+304D (き)
+    [ 0x44, 0x2F, 0x04, 0x1F, 0x0E, 0x01, 0x1E, 0x00],   // U+304E (ぎ)
+    [ 0x10, 0x08, 0x04, 0x02, 0x04, 0x08, 0x10, 0x00],   // U+304F (く)
+    [ 0x28, 0x44, 0x12, 0x21, 0x02, 0x04, 0x08, 0x00],   // U+3050 (ぐ)
+    [ 0x00, 0x22, 0x79, 0x21, 0x21, 0x22, 0x10, 0x00],   // U+3051 (け)
+    [ 0x40, 0x22, 0x11, 0x3D, 0x11, 0x12, 0x08, 0x00],   // U+3052 (げ)
+    [ 0x00, 0x00, 0x3C, 0x00, 0x02, 0x02, 0x3C, 0x00],   // U+3053 (こ)
+    [ 0x20, 0x40, 0x16, 0x20, 0x01, 0x01, 0x0E, 0x00],   // U+3054 (ご)
+    [ 0x10, 0x7E, 0x10, 0x3C, 0x02, 0x02, 0x1C, 0x00],   // U+3055 (さ)
+    [ 0x24, 0x4F, 0x14, 0x2E, 0x01, 0x01, 0x0E, 0x00],   // U+3056 (ざ)
+    [ 0x00, 0x02, 0x02, 0x02, 0x42, 0x22, 0x1C, 0x00],   // U+3057 (し)
+    [ 0x20, 0x42, 0x12, 0x22, 0x02, 0x22, 0x1C, 0x00],   // U+3058 (じ)
+    [ 0x10, 0x7E, 0x18, 0x14, 0x18, 0x10, 0x0C, 0x00],   // U+3059 (す)
+    [ 0x44, 0x2F, 0x06, 0x05, 0x06, 0x04, 0x03, 0x00],   // U+305A (ず)
+    [ 0x20, 0x72, 0x2F, 0x22, 0x1A, 0x02, 0x1C, 0x00],   // U+305B (せ)
+    [ 0x80, 0x50, 0x3A, 0x17, 0x1A, 0x02, 0x1C, 0x00],   // U+305C (ぜ)
+    [ 0x1E, 0x08, 0x04, 0x7F, 0x08, 0x04, 0x38, 0x00],   // U+305D (そ)
+    [ 0x4F, 0x24, 0x02, 0x7F, 0x08, 0x04, 0x38, 0x00],   // U+305E (ぞ)
+    [ 0x02, 0x0F, 0x02, 0x72, 0x02, 0x09, 0x71, 0x00],   // U+305F (た)
+    [ 0x42, 0x2F, 0x02, 0x72, 0x02, 0x09, 0x71, 0x00],   // U+3060 (だ)
+    [ 0x08, 0x7E, 0x08, 0x3C, 0x40, 0x40, 0x38, 0x00],   // U+3061 (ち)
+    [ 0x44, 0x2F, 0x04, 0x1E, 0x20, 0x20, 0x1C, 0x00],   // U+3062 (ぢ)
+    [ 0x00, 0x00, 0x00, 0x1C, 0x22, 0x20, 0x1C, 0x00],   // U+3063 (っ)
+    [ 0x00, 0x1C, 0x22, 0x41, 0x40, 0x20, 0x1C, 0x00],   // U+3064 (つ)
+    [ 0x40, 0x20, 0x1E, 0x21, 0x20, 0x20, 0x1C, 0x00],   // U+3065 (づ)
+    [ 0x00, 0x3E, 0x08, 0x04, 0x04, 0x04, 0x38, 0x00],   // U+3066 (て)
+    [ 0x00, 0x3E, 0x48, 0x24, 0x04, 0x04, 0x38, 0x00],   // U+3067 (で)
+    [ 0x04, 0x04, 0x08, 0x3C, 0x02, 0x02, 0x3C, 0x00],   // U+3068 (と)
+    [ 0x44, 0x24, 0x08, 0x3C, 0x02, 0x02, 0x3C, 0x00],   // U+3069 (ど)
+    [ 0x32, 0x02, 0x27, 0x22, 0x72, 0x29, 0x11, 0x00],   // U+306A (な)
+    [ 0x00, 0x02, 0x7A, 0x02, 0x0A, 0x72, 0x02, 0x00],   // U+306B (に)
+    [ 0x08, 0x09, 0x3E, 0x4B, 0x65, 0x55, 0x22, 0x00],   // U+306C (ぬ)
+    [ 0x04, 0x07, 0x34, 0x4C, 0x66, 0x54, 0x24, 0x00],   // U+306D (ね)
+    [ 0x00, 0x00, 0x3C, 0x4A, 0x49, 0x45, 0x22, 0x00],   // U+306E (の)
+    [ 0x00, 0x22, 0x7A, 0x22, 0x72, 0x2A, 0x12, 0x00],   // U+306F (は)
+    [ 0x80, 0x51, 0x1D, 0x11, 0x39, 0x15, 0x09, 0x00],   // U+3070 (ば)
+    [ 0x40, 0xB1, 0x5D, 0x11, 0x39, 0x15, 0x09, 0x00],   // U+3071 (ぱ)
+    [ 0x00, 0x00, 0x13, 0x32, 0x51, 0x11, 0x0E, 0x00],   // U+3072 (ひ)
+    [ 0x40, 0x20, 0x03, 0x32, 0x51, 0x11, 0x0E, 0x00],   // U+3073 (び)
+    [ 0x40, 0xA0, 0x43, 0x32, 0x51, 0x11, 0x0E, 0x00],   // U+3074 (ぴ)
+    [ 0x1C, 0x00, 0x08, 0x2A, 0x49, 0x10, 0x0C, 0x00],   // U+3075 (ふ)
+    [ 0x4C, 0x20, 0x08, 0x2A, 0x49, 0x10, 0x0C, 0x00],   // U+3076 (ぶ)
+    [ 0x4C, 0xA0, 0x48, 0x0A, 0x29, 0x48, 0x0C, 0x00],   // U+3077 (ぷ)
+    [ 0x00, 0x00, 0x04, 0x0A, 0x11, 0x20, 0x40, 0x00],   // U+3078 (へ)
+    [ 0x20, 0x40, 0x14, 0x2A, 0x11, 0x20, 0x40, 0x00],   // U+3079 (べ)
+    [ 0x20, 0x50, 0x24, 0x0A, 0x11, 0x20, 0x40, 0x00],   // U+307A (ぺ)
+    [ 0x7D, 0x11, 0x7D, 0x11, 0x39, 0x55, 0x09, 0x00],   // U+307B (ほ)
+    [ 0x9D, 0x51, 0x1D, 0x11, 0x39, 0x55, 0x09, 0x00],   // U+307C (ぼ)
+    [ 0x5D, 0xB1, 0x5D, 0x11, 0x39, 0x55, 0x09, 0x00],   // U+307D (ぽ)
+    [ 0x7E, 0x08, 0x3E, 0x08, 0x1C, 0x2A, 0x04, 0x00],   // U+307E (ま)
+    [ 0x00, 0x07, 0x24, 0x24, 0x7E, 0x25, 0x12, 0x00],   // U+307F (み)
+    [ 0x04, 0x0F, 0x64, 0x06, 0x05, 0x26, 0x3C, 0x00],   // U+3080 (む)
+    [ 0x00, 0x09, 0x3D, 0x4A, 0x4B, 0x45, 0x2A, 0x00],   // U+3081 (め)
+    [ 0x02, 0x0F, 0x02, 0x0F, 0x62, 0x42, 0x3C, 0x00],   // U+3082 (も)
+    [ 0x00, 0x00, 0x12, 0x1F, 0x22, 0x12, 0x04, 0x00],   // U+3083 (ゃ)
+    [ 0x00, 0x12, 0x3F, 0x42, 0x42, 0x34, 0x04, 0x00],   // U+3084 (や)
+    [ 0x00, 0x00, 0x11, 0x3D, 0x53, 0x39, 0x11, 0x00],   // U+3085 (ゅ)
+    [ 0x00, 0x11, 0x3D, 0x53, 0x51, 0x39, 0x11, 0x00],   // U+3086 (ゆ)
+    [ 0x00, 0x08, 0x38, 0x08, 0x1C, 0x2A, 0x04, 0x00],   // U+3087 (ょ)
+    [ 0x08, 0x08, 0x38, 0x08, 0x1C, 0x2A, 0x04, 0x00],   // U+3088 (よ)
+    [ 0x1E, 0x00, 0x02, 0x3A, 0x46, 0x42, 0x30, 0x00],   // U+3089 (ら)
+    [ 0x00, 0x20, 0x22, 0x22, 0x2A, 0x24, 0x10, 0x00],   // U+308A (り)
+    [ 0x1F, 0x08, 0x3C, 0x42, 0x49, 0x54, 0x38, 0x00],   // U+308B (る)
+    [ 0x04, 0x07, 0x04, 0x0C, 0x16, 0x55, 0x24, 0x00],   // U+308C (れ)
+    [ 0x3F, 0x10, 0x08, 0x3C, 0x42, 0x41, 0x30, 0x00],   // U+308D (ろ)
+    [ 0x00, 0x00, 0x08, 0x0E, 0x38, 0x4C, 0x2A, 0x00],   // U+308E (ゎ)
+    [ 0x04, 0x07, 0x04, 0x3C, 0x46, 0x45, 0x24, 0x00],   // U+308F (わ)
+    [ 0x0E, 0x08, 0x3C, 0x4A, 0x69, 0x55, 0x32, 0x00],   // U+3090 (ゐ)
+    [ 0x06, 0x3C, 0x42, 0x39, 0x04, 0x36, 0x49, 0x00],   // U+3091 (ゑ)
+    [ 0x04, 0x0F, 0x04, 0x6E, 0x11, 0x08, 0x70, 0x00],   // U+3092 (を)
+    [ 0x08, 0x08, 0x04, 0x0C, 0x56, 0x52, 0x21, 0x00],   // U+3093 (ん)
+    [ 0x40, 0x2E, 0x00, 0x3C, 0x42, 0x40, 0x38, 0x00],   // U+3094 (ゔ)
+    [ 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],   // U+3095 (ゕ)
+    [ 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],   // U+3096 (ゖ)
+    [ 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],   // U+3097
+    [ 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],   // U+3098
+    [ 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],   // U+3099
+    [ 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],   // U+309A
+    [ 0x40, 0x80, 0x20, 0x40, 0x00, 0x00, 0x00, 0x00],   // U+309B (゛)
+    [ 0x40, 0xA0, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00],   // U+309C (゜)
+    [ 0x00, 0x00, 0x08, 0x08, 0x10, 0x30, 0x0C, 0x00],   // U+309D (ゝ)
+    [ 0x20, 0x40, 0x14, 0x24, 0x08, 0x18, 0x06, 0x00],   // U+309E (ゞ)
+    [ 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]    // U+309F (ゟ)
+];
+// Drawn for characters none of the sets cover
+pub const FONT8X8_FALLBACK: [u8; 8] = [ 0x7F, 0x5D, 0x51, 0x49, 0x41, 0x49, 0x7F, 0x00];
+
+/// The built in glyph for `c`, characters none of the sets cover get [FONT8X8_FALLBACK]
+pub(crate) fn glyph8x8(c: char) -> &'static [u8; 8] {
+    let code = c as usize;
+    match code {
+        0x00..=0x7F => &super::FONT8X8_BASIC[code],
+        0xA0..=0xFF => &FONT8X8_LATIN[code - 0xA0],
+        0x0390..=0x03C9 if code != 0x03A2 => &FONT8X8_GREEK[code - 0x0390],
+        0x2500..=0x257F => &FONT8X8_BOX[code - 0x2500],
+        0x2580..=0x259F => &FONT8X8_BLOCK[code - 0x2580],
+        0x3041..=0x3094 | 0x3099..=0x309E => &FONT8X8_HIRAGANA[code - 0x3040],
+        _ => &FONT8X8_FALLBACK,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_set_is_reachable() {
+        assert_eq!(glyph8x8('A'), &[0x0C, 0x1E, 0x33, 0x33, 0x3F, 0x33, 0x33, 0x00]);
+        assert_eq!(glyph8x8('é'), &FONT8X8_LATIN[0xE9 - 0xA0]);
+        assert_eq!(glyph8x8('Ω'), &FONT8X8_GREEK[0x03A9 - 0x0390]);
+        assert_eq!(glyph8x8('─'), &[0, 0, 0, 0, 0xFF, 0, 0, 0]);
+        assert_eq!(glyph8x8('█'), &[0xFF; 8]);
+        assert_eq!(glyph8x8('▌'), &[0x0F; 8]);
+        assert_eq!(glyph8x8('あ'), &FONT8X8_HIRAGANA[0x3042 - 0x3040]);
+    }
+
+    #[test]
+    fn rounded_corners_point_the_right_way() {
+        // Bit 0 is the leftmost pixel, so the right corners curve in from the left
+        assert_eq!(glyph8x8('╭'), &[0, 0, 0, 0, 0xE0, 0x10, 0x08, 0x08]);
+        assert_eq!(glyph8x8('╮'), &[0, 0, 0, 0, 0x03, 0x04, 0x08, 0x08]);
+        assert_eq!(glyph8x8('╯'), &[0x08, 0x08, 0x08, 0x04, 0x03, 0, 0, 0]);
+        assert_eq!(glyph8x8('╰'), &[0x08, 0x08, 0x08, 0x10, 0xE0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn accents_tell_letters_apart() {
+        let variants = ['A', 'À', 'Á', 'Â', 'Ã', 'Ä', 'Å'].map(glyph8x8);
+        for (i, a) in variants.iter().enumerate() {
+            for b in &variants[i + 1..] {
+                assert_ne!(a, b);
+            }
+        }
+    }
+
+    #[test]
+    fn uncovered_characters_get_the_fallback() {
+        for c in ['\u{80}', '\u{03A2}', '\u{3040}', 'ゖ', 'ゟ', '€', '中', '😀'] {
+            assert_eq!(glyph8x8(c), &FONT8X8_FALLBACK, "{:?}", c);
+        }
+        // Spaces are covered, they're just empty
+        assert_eq!(glyph8x8('\u{A0}'), &[0; 8]);
+    }
+}
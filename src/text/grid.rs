@@ -0,0 +1,282 @@
+use crate::color::Color;
+
+/// A single cell of a [CharGrid]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Cell {
+    pub glyph: char,
+    pub fg: Color,
+    pub bg: Color,
+}
+
+impl Cell {
+    /// An empty cell with the background `bg`
+    pub const fn blank(fg: Color, bg: Color) -> Self {
+        Self { glyph: ' ', fg, bg }
+    }
+}
+
+/// A terminal like grid of `cols` x `rows` character cells, drawn with the built in 8x8 font by [Graphics::char_grid](crate::Graphics::char_grid). <br>
+/// Printing goes through a cursor that wraps at the end of a row and scrolls the grid when it runs off the bottom.
+/// **New grids are white on black**
+#[derive(Clone, Debug, PartialEq)]
+pub struct CharGrid {
+    cols: usize,
+    rows: usize,
+    cells: Vec<Cell>,
+    cursor: (usize, usize),
+    cursor_visible: bool,
+    fg: Color,
+    bg: Color,
+}
+
+impl CharGrid {
+    pub fn new(cols: usize, rows: usize) -> Self {
+        Self {
+            cols,
+            rows,
+            cells: vec![Cell::blank(Color::WHITE, Color::BLACK); cols * rows],
+            cursor: (0, 0),
+            cursor_visible: false,
+            fg: Color::WHITE,
+            bg: Color::BLACK,
+        }
+    }
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// The cell at `col`, `row`, `None` if it's outside of the grid
+    pub fn cell(&self, col: usize, row: usize) -> Option<&Cell> {
+        self.index(col, row).map(|i| &self.cells[i])
+    }
+    pub fn cell_mut(&mut self, col: usize, row: usize) -> Option<&mut Cell> {
+        self.index(col, row).map(|i| &mut self.cells[i])
+    }
+    /// Overwrite a cell, does nothing if it's outside of the grid
+    pub fn set(&mut self, col: usize, row: usize, cell: Cell) {
+        if let Some(target) = self.cell_mut(col, row) {
+            *target = cell;
+        }
+    }
+    /// All cells row by row
+    pub fn cells(&self) -> &[Cell] {
+        &self.cells
+    }
+
+    /// Colors used by everything printed from now on and by rows that scroll in
+    pub fn set_colors(&mut self, fg: Color, bg: Color) {
+        self.fg = fg;
+        self.bg = bg;
+    }
+    pub fn colors(&self) -> (Color, Color) {
+        (self.fg, self.bg)
+    }
+
+    /// Blank every cell with the current colors and move the cursor home
+    pub fn clear(&mut self) {
+        self.cells.fill(Cell::blank(self.fg, self.bg));
+        self.cursor = (0, 0);
+    }
+
+    /// Column and row the next [CharGrid::print] starts at
+    pub fn cursor(&self) -> (usize, usize) {
+        self.cursor
+    }
+    /// Move the cursor, it's clamped to the grid
+    pub fn set_cursor(&mut self, col: usize, row: usize) {
+        self.cursor = (col.min(self.cols.saturating_sub(1)), row.min(self.rows.saturating_sub(1)));
+    }
+    /// Draw the cursor as an inverted cell
+    pub fn show_cursor(&mut self, visible: bool) {
+        self.cursor_visible = visible;
+    }
+    pub fn cursor_visible(&self) -> bool {
+        self.cursor_visible
+    }
+
+    /// Write `text` starting at `col`, `row` with the current colors. <br>
+    /// Doesn't move the cursor, wrap or scroll, whatever doesn't fit in the row is cut off.
+    pub fn print_at(&mut self, col: usize, row: usize, text: &str) {
+        if row >= self.rows {
+            return;
+        }
+        for (i, glyph) in text.chars().enumerate() {
+            if col + i >= self.cols {
+                break;
+            }
+            self.set(col + i, row, Cell { glyph, fg: self.fg, bg: self.bg });
+        }
+    }
+
+    /// Write `text` at the cursor like a terminal would. <br>
+    /// `\n` goes to the start of the next line, `\r` to the start of the current one and `\t` to the next multiple of 4 columns.
+    pub fn print(&mut self, text: &str) {
+        if self.cols == 0 || self.rows == 0 {
+            return;
+        }
+        for c in text.chars() {
+            match c {
+                '\n' => self.new_line(),
+                '\r' => self.cursor.0 = 0,
+                '\t' => {
+                    let stop = (self.cursor.0 / 4 + 1) * 4;
+                    while self.cursor.0 < stop.min(self.cols) {
+                        self.put(' ');
+                    }
+                }
+                c => self.put(c),
+            }
+        }
+    }
+
+    /// Move every row up by `lines`, rows coming in at the bottom are blanked with the current colors
+    pub fn scroll(&mut self, lines: usize) {
+        let lines = lines.min(self.rows);
+        self.cells.drain(..lines * self.cols);
+        self.cells.resize(self.cols * self.rows, Cell::blank(self.fg, self.bg));
+    }
+
+    fn put(&mut self, glyph: char) {
+        // Wrap only once something is actually printed past the end of the row
+        if self.cursor.0 >= self.cols {
+            self.new_line();
+        }
+        let (col, row) = self.cursor;
+        self.set(col, row, Cell { glyph, fg: self.fg, bg: self.bg });
+        self.cursor.0 += 1;
+    }
+
+    fn new_line(&mut self) {
+        self.cursor.0 = 0;
+        if self.cursor.1 + 1 >= self.rows {
+            self.scroll(1);
+        } else {
+            self.cursor.1 += 1;
+        }
+    }
+
+    fn index(&self, col: usize, row: usize) -> Option<usize> {
+        (col < self.cols && row < self.rows).then(|| row * self.cols + col)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(grid: &CharGrid, row: usize) -> String {
+        (0..grid.cols()).map(|col| grid.cell(col, row).unwrap().glyph).collect()
+    }
+    fn rows(grid: &CharGrid) -> Vec<String> {
+        (0..grid.rows()).map(|r| row(grid, r)).collect()
+    }
+
+    #[test]
+    fn out_of_bounds() {
+        let mut grid = CharGrid::new(4, 2);
+        let before = grid.clone();
+        let x = Cell { glyph: 'x', fg: Color::RED, bg: Color::BLUE };
+        grid.set(4, 0, x);
+        grid.set(0, 2, x);
+        grid.set(usize::MAX, usize::MAX, x);
+        grid.print_at(0, 2, "nope");
+        grid.print_at(usize::MAX, 0, "nope");
+        assert_eq!(grid, before);
+        assert_eq!(grid.cell(4, 0), None);
+        assert_eq!(grid.cell(0, 2), None);
+        assert!(grid.cell_mut(usize::MAX, 1).is_none());
+
+        grid.set(3, 1, x);
+        assert_eq!(grid.cell(3, 1), Some(&x));
+
+        // Cut off at the end of the row, never wrapped into the next one
+        grid.print_at(2, 0, "abcdef");
+        assert_eq!(rows(&grid), ["  ab", "   x"]);
+        assert_eq!(grid.cursor(), (0, 0));
+
+        grid.set_cursor(10, 10);
+        assert_eq!(grid.cursor(), (3, 1));
+    }
+
+    #[test]
+    fn empty_grids() {
+        let mut grid = CharGrid::new(0, 3);
+        grid.print("hello\n\tworld");
+        grid.print_at(0, 0, "hi");
+        grid.scroll(2);
+        grid.set_cursor(5, 5);
+        assert!(grid.cells().is_empty());
+
+        let mut grid = CharGrid::new(3, 0);
+        grid.print("hello");
+        grid.scroll(1);
+        assert!(grid.cells().is_empty());
+    }
+
+    #[test]
+    fn wrapping() {
+        let mut grid = CharGrid::new(4, 3);
+        grid.print("abcd");
+        // A full row leaves the cursor past the end instead of starting a new line right away
+        assert_eq!(grid.cursor(), (4, 0));
+        assert_eq!(rows(&grid), ["abcd", "    ", "    "]);
+        grid.print("ef");
+        assert_eq!(grid.cursor(), (2, 1));
+        assert_eq!(rows(&grid), ["abcd", "ef  ", "    "]);
+
+        // So a newline right at the end of a row doesn't leave an empty line
+        grid.print("gh\nij");
+        assert_eq!(rows(&grid), ["abcd", "efgh", "ij  "]);
+    }
+
+    #[test]
+    fn scrolling() {
+        let mut grid = CharGrid::new(3, 3);
+        grid.print("a\nb\nc");
+        assert_eq!(grid.cursor(), (1, 2));
+        grid.set_colors(Color::GREEN, Color::RED);
+        grid.print("\nd");
+        assert_eq!(rows(&grid), ["b  ", "c  ", "d  "]);
+        assert_eq!(grid.cursor(), (1, 2));
+        // The new row is blanked with the current colors
+        assert_eq!(grid.cell(2, 2), Some(&Cell::blank(Color::GREEN, Color::RED)));
+        assert_eq!(grid.cell(0, 2), Some(&Cell { glyph: 'd', fg: Color::GREEN, bg: Color::RED }));
+        assert_eq!(grid.cell(0, 0).unwrap().bg, Color::BLACK);
+
+        // Wrapping past the last row scrolls too
+        grid.print("efgh");
+        assert_eq!(rows(&grid), ["c  ", "def", "gh "]);
+
+        grid.scroll(1);
+        assert_eq!(rows(&grid), ["def", "gh ", "   "]);
+        grid.scroll(100);
+        assert_eq!(rows(&grid), ["   ", "   ", "   "]);
+        assert_eq!(grid.cells().len(), 9);
+    }
+
+    #[test]
+    fn control_characters() {
+        let mut grid = CharGrid::new(10, 2);
+        grid.print("abc\rX");
+        assert_eq!(row(&grid, 0), "Xbc       ");
+        assert_eq!(grid.cursor(), (1, 0));
+
+        grid.print("\tY\tZ");
+        // Tabs fill the cells they skip with spaces
+        assert_eq!(row(&grid, 0), "X   Y   Z ");
+        assert_eq!(grid.cursor(), (9, 0));
+
+        // Tab stops past the last column stop at the edge, the next character wraps
+        grid.print("\t");
+        assert_eq!(grid.cursor(), (10, 0));
+        grid.print("\tW");
+        assert_eq!(rows(&grid), ["X   Y   Z ", "W         "]);
+
+        grid.clear();
+        assert_eq!(grid.cursor(), (0, 0));
+        assert!(grid.cells().iter().all(|cell| cell.glyph == ' '));
+    }
+}
@@ -0,0 +1,124 @@
+/// Horizontal placement of text relative to its anchor
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+pub enum HAlign {
+    /// Text starts at the anchor
+    #[default]
+    Left,
+    /// Text is centered on the anchor
+    Center,
+    /// Text ends at the anchor
+    Right,
+}
+
+/// Vertical placement of text relative to its anchor
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+pub enum VAlign {
+    /// The top of the line is at the anchor
+    #[default]
+    Top,
+    /// The line is centered on the anchor
+    Middle,
+    /// The baseline is at the anchor, handy for lining up text drawn with different fonts
+    Baseline,
+    /// The bottom of the line is at the anchor
+    Bottom,
+}
+
+/// Where text is placed relative to a point or inside a [Rect](crate::Rect). <br>
+/// **Defaults to top left**
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+pub struct TextAlign {
+    pub horizontal: HAlign,
+    pub vertical: VAlign,
+}
+
+impl TextAlign {
+    pub const TOP_LEFT: Self = Self::new(HAlign::Left, VAlign::Top);
+    pub const CENTER: Self = Self::new(HAlign::Center, VAlign::Middle);
+
+    pub const fn new(horizontal: HAlign, vertical: VAlign) -> Self {
+        Self { horizontal, vertical }
+    }
+}
+
+/// Split `text` at `\n`, a `\r` right before it is part of the line break
+pub(crate) fn lines(text: &str) -> impl Iterator<Item = &str> + Clone {
+    text.split('\n').map(|line| line.strip_suffix('\r').unwrap_or(line))
+}
+
+/// Greedily break `text` into lines no wider than `max_width`. <br>
+/// Lines are only broken at single spaces, so tabs and runs of spaces are kept as they are unless a line breaks on them.
+/// Words that don't fit on a line by themselves are split between characters, newlines always start a new line.
+pub(crate) fn wrap(text: &str, max_width: i32, width: impl Fn(&str) -> i32) -> Vec<String> {
+    let mut lines = Vec::new();
+    for paragraph in self::lines(text) {
+        // `None` until the first word of the line, an empty word is a space next to another one
+        let mut line: Option<String> = None;
+        let mut wrapped = false;
+        for word in paragraph.split(' ') {
+            // Spaces at a line break disappear with it
+            if word.is_empty() && wrapped && line.is_none() {
+                continue;
+            }
+            let candidate = match &line {
+                Some(line) => format!("{line} {word}"),
+                None => word.to_string(),
+            };
+            if width(&candidate) <= max_width {
+                line = Some(candidate);
+                continue;
+            }
+            if let Some(full) = line.take() {
+                lines.push(full.trim_end_matches(' ').to_string());
+                wrapped = true;
+            }
+            if word.is_empty() {
+                continue;
+            }
+            let mut part = String::new();
+            for c in word.chars() {
+                part.push(c);
+                if width(&part) > max_width && part.chars().count() > 1 {
+                    part.pop();
+                    lines.push(std::mem::replace(&mut part, c.to_string()));
+                    wrapped = true;
+                }
+            }
+            line = Some(part);
+        }
+        lines.push(line.unwrap_or_default());
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::wrap;
+
+    /// One pixel per character, tabs jump to the next multiple of 4
+    fn width(line: &str) -> i32 {
+        line.chars().fold(0, |x, c| if c == '\t' { (x / 4 + 1) * 4 } else { x + 1 })
+    }
+
+    #[test]
+    fn breaks_between_words() {
+        assert_eq!(wrap("the quick brown fox", 10, width), ["the quick", "brown fox"]);
+        assert_eq!(wrap("the quick\n\nfox", 20, width), ["the quick", "", "fox"]);
+        assert_eq!(wrap("", 5, width), [""]);
+    }
+
+    #[test]
+    fn long_words_are_split() {
+        assert_eq!(wrap("a abcdefgh", 3, width), ["a", "abc", "def", "gh"]);
+    }
+
+    #[test]
+    fn tabs_and_repeated_spaces_are_kept() {
+        assert_eq!(wrap("a\tb  c", 20, width), ["a\tb  c"]);
+        assert_eq!(wrap("  indented", 20, width), ["  indented"]);
+        // The tab counts towards the width, the spaces at a break go away
+        assert_eq!(wrap("aa\tbb cc   dd", 6, width), ["aa\tbb", "cc", "dd"]);
+        assert_eq!(wrap("aa\tbb", 5, width), ["aa\tb", "b"]);
+        assert_eq!(wrap("key:\tvalue  next", 13, width), ["key:\tvalue", "next"]);
+    }
+}
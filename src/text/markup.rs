@@ -0,0 +1,157 @@
+use crate::color::Color;
+
+/// Split text with color tags into colored spans. <br>
+/// `{red}` or `{#ff8800}` switches color, `{/}` goes back to the color before it and `{{` is a literal `{`.
+/// Anything in braces that isn't a known color is kept as plain text.
+pub(crate) fn parse(text: &str, color: Color) -> Vec<(String, Color)> {
+    let mut spans: Vec<(String, Color)> = Vec::new();
+    let mut stack = vec![color];
+    let mut current = String::new();
+    let mut rest = text;
+
+    while let Some(start) = rest.find('{') {
+        current.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        if rest.starts_with("{{") {
+            current.push('{');
+            rest = &rest[2..];
+            continue;
+        }
+
+        let tag = rest[1..].find('}').map(|end| &rest[1..end + 1]);
+        let next = match tag {
+            Some("/") => None,
+            Some(tag) => match parse_color(tag) {
+                Some(color) => Some(color),
+                None => {
+                    current.push('{');
+                    rest = &rest[1..];
+                    continue;
+                }
+            },
+            None => {
+                current.push_str(rest);
+                rest = "";
+                break;
+            }
+        };
+
+        let active = *stack.last().unwrap_or(&color);
+        if !current.is_empty() {
+            spans.push((std::mem::take(&mut current), active));
+        }
+        match next {
+            Some(color) => stack.push(color),
+            // The first color can't be popped, stray closing tags are ignored
+            None if stack.len() > 1 => { stack.pop(); }
+            None => {}
+        }
+        rest = &rest[tag.map_or(0, str::len) + 2..];
+    }
+
+    current.push_str(rest);
+    if !current.is_empty() {
+        spans.push((current, *stack.last().unwrap_or(&color)));
+    }
+    spans
+}
+
+/// Color names match the [Color] constants, hex colors can be `#rgb`, `#rrggbb` or `#rrggbbaa`
+fn parse_color(tag: &str) -> Option<Color> {
+    if let Some(hex) = tag.strip_prefix('#') {
+        if !hex.is_ascii() {
+            return None;
+        }
+        let channel = |i: usize, len: usize| u8::from_str_radix(&hex[i * len..(i + 1) * len], 16).ok();
+        return match hex.len() {
+            3 => Some(Color::rgb(channel(0, 1)? * 17, channel(1, 1)? * 17, channel(2, 1)? * 17)),
+            6 => Some(Color::rgb(channel(0, 2)?, channel(1, 2)?, channel(2, 2)?)),
+            8 => Some(Color { a: channel(3, 2)?, ..Color::rgb(channel(0, 2)?, channel(1, 2)?, channel(2, 2)?) }),
+            _ => None,
+        };
+    }
+    match tag.to_ascii_lowercase().as_str() {
+        "black" => Some(Color::BLACK),
+        "white" => Some(Color::WHITE),
+        "red" => Some(Color::RED),
+        "green" => Some(Color::GREEN),
+        "blue" => Some(Color::BLUE),
+        "yellow" => Some(Color::YELLOW),
+        "cyan" => Some(Color::CYAN),
+        "magenta" => Some(Color::MAGENTA),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spans(text: &str) -> Vec<(String, Color)> {
+        parse(text, Color::WHITE)
+    }
+    fn span(text: &str, color: Color) -> (String, Color) {
+        (text.to_string(), color)
+    }
+
+    #[test]
+    fn plain_text() {
+        assert_eq!(spans(""), vec![]);
+        assert_eq!(spans("héllo wörld"), vec![span("héllo wörld", Color::WHITE)]);
+    }
+
+    #[test]
+    fn nested_tags() {
+        assert_eq!(
+            spans("a{red}b{blue}c{/}d{/}e"),
+            vec![
+                span("a", Color::WHITE),
+                span("b", Color::RED),
+                span("c", Color::BLUE),
+                span("d", Color::RED),
+                span("e", Color::WHITE),
+            ]
+        );
+        // Tags right next to each other don't make empty spans
+        assert_eq!(spans("{red}{green}ü{/}{/}"), vec![span("ü", Color::GREEN)]);
+        assert_eq!(parse("{/}x{Yellow}y", Color::BLUE), vec![span("x", Color::BLUE), span("y", Color::YELLOW)]);
+    }
+
+    #[test]
+    fn stray_closing_tags() {
+        assert_eq!(spans("a{/}{/}b"), vec![span("a", Color::WHITE), span("b", Color::WHITE)]);
+        assert_eq!(spans("{red}a{/}{/}{/}b"), vec![span("a", Color::RED), span("b", Color::WHITE)]);
+    }
+
+    #[test]
+    fn unterminated_tags() {
+        assert_eq!(spans("a{red"), vec![span("a{red", Color::WHITE)]);
+        assert_eq!(spans("{red}a{green"), vec![span("a{green", Color::RED)]);
+        assert_eq!(spans("{"), vec![span("{", Color::WHITE)]);
+        assert_eq!(spans("x{/"), vec![span("x{/", Color::WHITE)]);
+    }
+
+    #[test]
+    fn unknown_tags_stay_as_text() {
+        assert_eq!(spans("{}{nope}{#ff88}{#gg0000}{#é00}"), vec![span("{}{nope}{#ff88}{#gg0000}{#é00}", Color::WHITE)]);
+        // Only the text up to the first closing brace is the tag, a real tag after it still works
+        assert_eq!(spans("{nope {red}x"), vec![span("{nope ", Color::WHITE), span("x", Color::RED)]);
+        assert_eq!(spans("a}b"), vec![span("a}b", Color::WHITE)]);
+    }
+
+    #[test]
+    fn escapes() {
+        assert_eq!(spans("{{red}"), vec![span("{red}", Color::WHITE)]);
+        assert_eq!(spans("{{{red}x{{{/}"), vec![span("{", Color::WHITE), span("x{", Color::RED)]);
+        assert_eq!(spans("{{{{"), vec![span("{{", Color::WHITE)]);
+    }
+
+    #[test]
+    fn colors() {
+        assert_eq!(spans("{#f80}a"), vec![span("a", Color::rgb(255, 136, 0))]);
+        assert_eq!(spans("{#1A2b3C}a"), vec![span("a", Color::rgb(0x1a, 0x2b, 0x3c))]);
+        assert_eq!(spans("{#ff880080}a"), vec![span("a", Color { a: 0x80, ..Color::rgb(255, 136, 0) })]);
+        assert_eq!(spans("{MAGENTA}a{cyan}b"), vec![span("a", Color::MAGENTA), span("b", Color::CYAN)]);
+    }
+}
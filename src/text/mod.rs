@@ -1,3 +1,59 @@
+use crate::vector::Vec2;
 
+mod bitmap;
 mod font8x8;
+mod font8x8_ext;
+mod grid;
+mod layout;
+mod markup;
+mod simplex;
+mod stroke;
+mod style;
+pub use bitmap::BitmapFont;
+pub use grid::{CharGrid, Cell};
+pub use layout::{HAlign, VAlign, TextAlign};
+pub(crate) use layout::{lines, wrap};
+pub(crate) use markup::parse as markup;
+pub use style::TextStyle;
+pub use stroke::StrokeStyle;
 pub use font8x8::FONT8X8_BASIC;
+pub(crate) use font8x8_ext::glyph8x8;
+#[cfg(feature = "ttf")]
+mod ttf;
+#[cfg(feature = "ttf")]
+pub use ttf::{Font, TextMode};
+
+/// Text rasterized into a single alpha mask
+#[derive(Clone)]
+pub(crate) struct TextMask {
+    /// Top left of the mask relative to the position the text was drawn at
+    pub offset: Vec2,
+    pub size: Vec2,
+    pub alpha: Vec<u8>,
+}
+
+impl TextMask {
+    pub(crate) fn empty() -> Self {
+        Self { offset: Vec2::new(0, 0), size: Vec2::new(0, 0), alpha: Vec::new() }
+    }
+    /// Blow every pixel up into a `sx` by `sy` block
+    pub(crate) fn scaled(self, sx: u32, sy: u32) -> Self {
+        if sx == 1 && sy == 1 {
+            return self;
+        }
+        let (sx, sy) = (sx as usize, sy as usize);
+        let width = self.size.x as usize;
+        let mut alpha = Vec::with_capacity(self.alpha.len() * sx * sy);
+        for row in self.alpha.chunks_exact(width.max(1)) {
+            let scaled: Vec<u8> = row.iter().flat_map(|a| std::iter::repeat_n(*a, sx)).collect();
+            for _ in 0..sy {
+                alpha.extend_from_slice(&scaled);
+            }
+        }
+        Self {
+            offset: Vec2::new(self.offset.x * sx as i32, self.offset.y * sy as i32),
+            size: Vec2::new(self.size.x * sx as i32, self.size.y * sy as i32),
+            alpha,
+        }
+    }
+}
@@ -0,0 +1,438 @@
+// Stroke font in the style of the Hershey Simplex font, drawn from scratch for the printable ASCII range.
+// Coordinates are in half units: the cap height is 42, the x-height starts at 14 and descenders reach down to 56.
+// Every glyph is its advance width followed by polylines, y grows downwards from the top of the capitals.
+
+/// Advance width and the polylines of a glyph
+pub type StrokeGlyph = (i8, &'static [&'static [(i8, i8)]]);
+
+/// Advance and strokes of the characters U+0020 - U+007E
+pub const SIMPLEX: [StrokeGlyph; 95] = [
+    // U+0020 (space)
+    (32, &[]),
+    // U+0021 (!)
+    (16, &[
+        &[(8, 0), (8, 28)],
+        &[(8, 40), (8, 42)],
+    ]),
+    // U+0022 (")
+    (24, &[
+        &[(8, 0), (8, 14)],
+        &[(16, 0), (16, 14)],
+    ]),
+    // U+0023 (#)
+    (42, &[
+        &[(20, 0), (14, 42)],
+        &[(30, 0), (24, 42)],
+        &[(8, 14), (34, 14)],
+        &[(6, 28), (32, 28)],
+    ]),
+    // U+0024 ($)
+    (40, &[
+        &[(30, 5), (27, 2), (23, 0), (19, 0), (15, 1), (11, 4), (9, 7), (8, 11), (9, 15), (12, 18), (16, 20), (20, 21), (24, 22), (28, 24), (31, 27), (32, 31), (31, 35), (29, 38), (25, 41), (21, 42), (17, 42), (13, 40), (10, 37)],
+        &[(20, -6), (20, 48)],
+    ]),
+    // U+0025 (%)
+    (48, &[
+        &[(38, 0), (10, 42)],
+        &[(20, 8), (20, 11), (18, 14), (16, 15), (14, 16), (12, 15), (10, 14), (8, 11), (8, 8), (8, 5), (10, 2), (12, 1), (14, 0), (16, 1), (18, 2), (20, 5), (20, 8)],
+        &[(40, 34), (40, 37), (38, 40), (36, 41), (34, 42), (32, 41), (30, 40), (28, 37), (28, 34), (28, 31), (30, 28), (32, 27), (34, 26), (36, 27), (38, 28), (40, 31), (40, 34)],
+    ]),
+    // U+0026 (&)
+    (48, &[
+        &[(38, 42), (14, 16), (12, 10), (14, 3), (19, 0), (24, 3), (26, 8), (24, 14), (10, 24), (8, 32), (10, 38), (16, 42), (24, 42), (30, 38), (36, 28)],
+    ]),
+    // U+0027 (')
+    (16, &[
+        &[(8, 0), (8, 14)],
+    ]),
+    // U+0028 (()
+    (24, &[
+        &[(21, -5), (17, 2), (15, 11), (14, 21), (15, 31), (17, 40), (21, 47)],
+    ]),
+    // U+0029 ())
+    (24, &[
+        &[(3, -5), (7, 2), (9, 11), (10, 21), (9, 31), (7, 40), (3, 47)],
+    ]),
+    // U+002A (*)
+    (32, &[
+        &[(16, 6), (16, 30)],
+        &[(6, 12), (26, 24)],
+        &[(26, 12), (6, 24)],
+    ]),
+    // U+002B (+)
+    (40, &[
+        &[(20, 10), (20, 38)],
+        &[(6, 24), (34, 24)],
+    ]),
+    // U+002C (,)
+    (16, &[
+        &[(8, 38), (8, 44), (6, 48)],
+    ]),
+    // U+002D (-)
+    (36, &[
+        &[(8, 24), (28, 24)],
+    ]),
+    // U+002E (.)
+    (16, &[
+        &[(8, 40), (8, 42)],
+    ]),
+    // U+002F (/)
+    (36, &[
+        &[(30, -6), (6, 48)],
+    ]),
+    // U+0030 (0)
+    (40, &[
+        &[(32, 21), (31, 29), (28, 36), (25, 40), (20, 42), (15, 40), (12, 36), (9, 29), (8, 21), (9, 13), (12, 6), (15, 2), (20, 0), (25, 2), (28, 6), (31, 13), (32, 21)],
+    ]),
+    // U+0031 (1)
+    (40, &[
+        &[(12, 8), (20, 0), (20, 42)],
+    ]),
+    // U+0032 (2)
+    (40, &[
+        &[(8, 10), (10, 6), (13, 3), (16, 1), (21, 0), (25, 1), (29, 4), (31, 7), (32, 12), (31, 16), (29, 20), (8, 42), (32, 42)],
+    ]),
+    // U+0033 (3)
+    (40, &[
+        &[(10, 0), (32, 0), (20, 16), (24, 17), (28, 20), (31, 24), (32, 28), (31, 33), (29, 38), (25, 41), (21, 42), (17, 41), (13, 39), (10, 36)],
+    ]),
+    // U+0034 (4)
+    (40, &[
+        &[(26, 42), (26, 0), (6, 30), (34, 30)],
+    ]),
+    // U+0035 (5)
+    (40, &[
+        &[(30, 0), (10, 0), (8, 18), (14, 18), (18, 16), (23, 16), (27, 19), (30, 22), (32, 27), (32, 32), (30, 37), (26, 40), (22, 42), (17, 42), (13, 39), (10, 36)],
+    ]),
+    // U+0036 (6)
+    (40, &[
+        &[(26, 3), (22, 0), (18, 0), (14, 3), (11, 8), (9, 14), (8, 21), (8, 30), (9, 25), (12, 22), (15, 19), (20, 18), (25, 19), (28, 22), (31, 25), (32, 30), (31, 35), (28, 38), (25, 41), (20, 42), (15, 41), (12, 38), (9, 35), (8, 30)],
+    ]),
+    // U+0037 (7)
+    (40, &[
+        &[(6, 0), (34, 0), (16, 42)],
+    ]),
+    // U+0038 (8)
+    (40, &[
+        &[(20, 20), (16, 19), (13, 17), (11, 14), (10, 10), (11, 6), (13, 3), (16, 1), (20, 0), (24, 1), (27, 3), (29, 6), (30, 10), (29, 14), (27, 17), (24, 19), (20, 20)],
+        &[(20, 20), (25, 21), (28, 23), (31, 27), (32, 31), (31, 35), (28, 39), (25, 41), (20, 42), (15, 41), (12, 39), (9, 35), (8, 31), (9, 27), (12, 23), (15, 21), (20, 20)],
+    ]),
+    // U+0039 (9)
+    (40, &[
+        &[(32, 12), (31, 17), (28, 20), (25, 23), (20, 24), (15, 23), (12, 20), (9, 17), (8, 12), (9, 7), (12, 4), (15, 1), (20, 0), (25, 1), (28, 4), (31, 7), (32, 12), (32, 21), (31, 28), (29, 34), (26, 39), (22, 42), (18, 42), (14, 39)],
+    ]),
+    // U+003A (:)
+    (16, &[
+        &[(8, 18), (8, 20)],
+        &[(8, 40), (8, 42)],
+    ]),
+    // U+003B (;)
+    (16, &[
+        &[(8, 18), (8, 20)],
+        &[(8, 38), (8, 44), (6, 48)],
+    ]),
+    // U+003C (<)
+    (40, &[
+        &[(32, 8), (8, 24), (32, 40)],
+    ]),
+    // U+003D (=)
+    (40, &[
+        &[(8, 18), (32, 18)],
+        &[(8, 30), (32, 30)],
+    ]),
+    // U+003E (>)
+    (40, &[
+        &[(8, 8), (32, 24), (8, 40)],
+    ]),
+    // U+003F (?)
+    (36, &[
+        &[(8, 8), (9, 5), (12, 2), (15, 0), (19, 0), (22, 1), (25, 3), (27, 6), (28, 10), (28, 13), (26, 16), (23, 19), (18, 22), (18, 28)],
+        &[(18, 40), (18, 42)],
+    ]),
+    // U+0040 (@)
+    (54, &[
+        &[(33, 23), (32, 26), (31, 29), (28, 31), (25, 32), (22, 31), (19, 29), (18, 26), (17, 23), (18, 20), (19, 17), (22, 15), (25, 14), (28, 15), (31, 17), (32, 20), (33, 23)],
+        &[(33, 14), (33, 28), (37, 31), (42, 28), (43, 27), (44, 19), (42, 12), (37, 6), (31, 3), (24, 2), (17, 5), (12, 10), (9, 17), (8, 25), (10, 32), (15, 38), (21, 41), (28, 42), (35, 39)],
+    ]),
+    // U+0041 (A)
+    (36, &[
+        &[(6, 42), (18, 0), (30, 42)],
+        &[(11, 28), (25, 28)],
+    ]),
+    // U+0042 (B)
+    (36, &[
+        &[(8, 42), (8, 0), (22, 0), (26, 1), (29, 3), (31, 6), (32, 10), (31, 14), (29, 17), (26, 19), (22, 20), (8, 20)],
+        &[(22, 20), (26, 21), (30, 23), (32, 27), (33, 31), (32, 35), (30, 39), (26, 41), (22, 42), (8, 42)],
+    ]),
+    // U+0043 (C)
+    (38, &[
+        &[(32, 8), (28, 3), (23, 0), (17, 1), (13, 4), (9, 10), (7, 17), (7, 25), (9, 32), (13, 38), (17, 41), (23, 42), (28, 39), (32, 34)],
+    ]),
+    // U+0044 (D)
+    (38, &[
+        &[(8, 0), (18, 0), (23, 2), (28, 6), (31, 13), (32, 21), (31, 29), (28, 36), (23, 40), (18, 42), (8, 42), (8, 0)],
+    ]),
+    // U+0045 (E)
+    (36, &[
+        &[(30, 0), (8, 0), (8, 42), (30, 42)],
+        &[(8, 20), (24, 20)],
+    ]),
+    // U+0046 (F)
+    (34, &[
+        &[(30, 0), (8, 0), (8, 42)],
+        &[(8, 20), (24, 20)],
+    ]),
+    // U+0047 (G)
+    (40, &[
+        &[(32, 8), (28, 3), (23, 0), (18, 1), (13, 4), (9, 9), (7, 17), (7, 24), (9, 32), (12, 38), (17, 41), (22, 42), (27, 40), (31, 35), (34, 29), (35, 21), (24, 21)],
+    ]),
+    // U+0048 (H)
+    (40, &[
+        &[(8, 0), (8, 42)],
+        &[(32, 0), (32, 42)],
+        &[(8, 20), (32, 20)],
+    ]),
+    // U+0049 (I)
+    (16, &[
+        &[(8, 0), (8, 42)],
+    ]),
+    // U+004A (J)
+    (32, &[
+        &[(24, 0), (24, 30), (23, 35), (22, 38), (19, 41), (16, 42), (13, 41), (10, 38), (9, 35), (8, 30)],
+    ]),
+    // U+004B (K)
+    (36, &[
+        &[(8, 0), (8, 42)],
+        &[(32, 0), (8, 28)],
+        &[(17, 20), (32, 42)],
+    ]),
+    // U+004C (L)
+    (32, &[
+        &[(8, 0), (8, 42), (30, 42)],
+    ]),
+    // U+004D (M)
+    (44, &[
+        &[(8, 42), (8, 0), (22, 42), (36, 0), (36, 42)],
+    ]),
+    // U+004E (N)
+    (40, &[
+        &[(8, 42), (8, 0), (32, 42), (32, 0)],
+    ]),
+    // U+004F (O)
+    (42, &[
+        &[(35, 21), (34, 29), (31, 36), (26, 40), (21, 42), (16, 40), (11, 36), (8, 29), (7, 21), (8, 13), (11, 6), (16, 2), (21, 0), (26, 2), (31, 6), (34, 13), (35, 21)],
+    ]),
+    // U+0050 (P)
+    (36, &[
+        &[(8, 42), (8, 0), (22, 0), (26, 1), (29, 3), (31, 7), (32, 11), (31, 15), (29, 19), (26, 21), (22, 22), (8, 22)],
+    ]),
+    // U+0051 (Q)
+    (42, &[
+        &[(35, 21), (34, 29), (31, 36), (26, 40), (21, 42), (16, 40), (11, 36), (8, 29), (7, 21), (8, 13), (11, 6), (16, 2), (21, 0), (26, 2), (31, 6), (34, 13), (35, 21)],
+        &[(24, 32), (36, 46)],
+    ]),
+    // U+0052 (R)
+    (36, &[
+        &[(8, 42), (8, 0), (22, 0), (26, 1), (29, 3), (31, 6), (32, 10), (31, 14), (29, 17), (26, 19), (22, 20), (8, 20)],
+        &[(20, 20), (32, 42)],
+    ]),
+    // U+0053 (S)
+    (40, &[
+        &[(30, 5), (27, 2), (23, 0), (19, 0), (15, 1), (11, 4), (9, 7), (8, 11), (9, 15), (12, 18), (16, 20), (20, 21), (24, 22), (28, 24), (31, 27), (32, 31), (31, 35), (29, 38), (25, 41), (21, 42), (17, 42), (13, 40), (10, 37)],
+    ]),
+    // U+0054 (T)
+    (40, &[
+        &[(6, 0), (34, 0)],
+        &[(20, 0), (20, 42)],
+    ]),
+    // U+0055 (U)
+    (40, &[
+        &[(8, 0), (8, 30), (9, 35), (12, 38), (15, 41), (20, 42), (25, 41), (28, 38), (31, 35), (32, 30), (32, 0)],
+    ]),
+    // U+0056 (V)
+    (40, &[
+        &[(6, 0), (20, 42), (34, 0)],
+    ]),
+    // U+0057 (W)
+    (52, &[
+        &[(6, 0), (16, 42), (26, 0), (36, 42), (46, 0)],
+    ]),
+    // U+0058 (X)
+    (40, &[
+        &[(8, 0), (32, 42)],
+        &[(32, 0), (8, 42)],
+    ]),
+    // U+0059 (Y)
+    (40, &[
+        &[(6, 0), (20, 20), (34, 0)],
+        &[(20, 20), (20, 42)],
+    ]),
+    // U+005A (Z)
+    (40, &[
+        &[(8, 0), (32, 0), (8, 42), (32, 42)],
+    ]),
+    // U+005B ([)
+    (24, &[
+        &[(18, -6), (8, -6), (8, 48), (18, 48)],
+    ]),
+    // U+005C (\)
+    (36, &[
+        &[(6, -6), (30, 48)],
+    ]),
+    // U+005D (])
+    (24, &[
+        &[(6, -6), (16, -6), (16, 48), (6, 48)],
+    ]),
+    // U+005E (^)
+    (36, &[
+        &[(6, 16), (18, 2), (30, 16)],
+    ]),
+    // U+005F (_)
+    (36, &[
+        &[(4, 52), (32, 52)],
+    ]),
+    // U+0060 (`)
+    (16, &[
+        &[(6, 0), (10, 8)],
+    ]),
+    // U+0061 (a)
+    (34, &[
+        &[(28, 28), (27, 33), (25, 38), (22, 41), (18, 42), (14, 41), (11, 38), (9, 33), (8, 28), (9, 23), (11, 18), (14, 15), (18, 14), (22, 15), (25, 18), (27, 23), (28, 28)],
+        &[(28, 14), (28, 42)],
+    ]),
+    // U+0062 (b)
+    (34, &[
+        &[(8, 0), (8, 42)],
+        &[(8, 28), (9, 23), (11, 18), (14, 15), (18, 14), (22, 15), (25, 18), (27, 23), (28, 28), (27, 33), (25, 38), (22, 41), (18, 42), (14, 41), (11, 38), (9, 33), (8, 28)],
+    ]),
+    // U+0063 (c)
+    (30, &[
+        &[(26, 19), (23, 16), (19, 14), (15, 14), (12, 17), (10, 21), (8, 25), (8, 31), (10, 35), (12, 39), (15, 42), (19, 42), (23, 40), (26, 37)],
+    ]),
+    // U+0064 (d)
+    (34, &[
+        &[(28, 0), (28, 42)],
+        &[(28, 28), (27, 33), (25, 38), (22, 41), (18, 42), (14, 41), (11, 38), (9, 33), (8, 28), (9, 23), (11, 18), (14, 15), (18, 14), (22, 15), (25, 18), (27, 23), (28, 28)],
+    ]),
+    // U+0065 (e)
+    (32, &[
+        &[(8, 28), (28, 28), (27, 23), (25, 19), (22, 15), (19, 14), (15, 15), (12, 17), (9, 21), (8, 26), (8, 31), (10, 36), (12, 39), (16, 42), (19, 42), (23, 40), (26, 37)],
+    ]),
+    // U+0066 (f)
+    (24, &[
+        &[(25, 4), (24, 2), (22, 0), (20, 0), (17, 1), (16, 3), (14, 5), (14, 8), (14, 42)],
+        &[(8, 14), (22, 14)],
+    ]),
+    // U+0067 (g)
+    (34, &[
+        &[(28, 28), (27, 33), (25, 38), (22, 41), (18, 42), (14, 41), (11, 38), (9, 33), (8, 28), (9, 23), (11, 18), (14, 15), (18, 14), (22, 15), (25, 18), (27, 23), (28, 28)],
+        &[(28, 14), (28, 48), (27, 51), (26, 53), (23, 55), (20, 56), (16, 56), (13, 54), (11, 52)],
+    ]),
+    // U+0068 (h)
+    (34, &[
+        &[(8, 0), (8, 42)],
+        &[(8, 22), (9, 19), (11, 16), (14, 15), (18, 14), (22, 15), (25, 16), (27, 19), (28, 22), (28, 42)],
+    ]),
+    // U+0069 (i)
+    (16, &[
+        &[(8, 14), (8, 42)],
+        &[(8, 3), (8, 5)],
+    ]),
+    // U+006A (j)
+    (20, &[
+        &[(12, 14), (12, 48), (12, 51), (11, 53), (9, 55), (7, 56), (5, 56), (3, 55)],
+        &[(12, 3), (12, 5)],
+    ]),
+    // U+006B (k)
+    (32, &[
+        &[(8, 0), (8, 42)],
+        &[(28, 14), (8, 32)],
+        &[(16, 25), (28, 42)],
+    ]),
+    // U+006C (l)
+    (16, &[
+        &[(8, 0), (8, 42)],
+    ]),
+    // U+006D (m)
+    (50, &[
+        &[(8, 14), (8, 42)],
+        &[(8, 22), (9, 19), (11, 16), (14, 15), (17, 14), (20, 15), (23, 16), (25, 19), (26, 22), (26, 42)],
+        &[(26, 22), (27, 19), (29, 16), (32, 15), (35, 14), (38, 15), (41, 16), (43, 19), (44, 22), (44, 42)],
+    ]),
+    // U+006E (n)
+    (34, &[
+        &[(8, 14), (8, 42)],
+        &[(8, 22), (9, 19), (11, 16), (14, 15), (18, 14), (22, 15), (25, 16), (27, 19), (28, 22), (28, 42)],
+    ]),
+    // U+006F (o)
+    (34, &[
+        &[(28, 28), (27, 33), (25, 38), (22, 41), (18, 42), (14, 41), (11, 38), (9, 33), (8, 28), (9, 23), (11, 18), (14, 15), (18, 14), (22, 15), (25, 18), (27, 23), (28, 28)],
+    ]),
+    // U+0070 (p)
+    (34, &[
+        &[(8, 14), (8, 56)],
+        &[(8, 28), (9, 23), (11, 18), (14, 15), (18, 14), (22, 15), (25, 18), (27, 23), (28, 28), (27, 33), (25, 38), (22, 41), (18, 42), (14, 41), (11, 38), (9, 33), (8, 28)],
+    ]),
+    // U+0071 (q)
+    (34, &[
+        &[(28, 14), (28, 56)],
+        &[(28, 28), (27, 33), (25, 38), (22, 41), (18, 42), (14, 41), (11, 38), (9, 33), (8, 28), (9, 23), (11, 18), (14, 15), (18, 14), (22, 15), (25, 18), (27, 23), (28, 28)],
+    ]),
+    // U+0072 (r)
+    (26, &[
+        &[(8, 14), (8, 42)],
+        &[(8, 22), (9, 19), (10, 17), (13, 15), (16, 14), (20, 14), (23, 15)],
+    ]),
+    // U+0073 (s)
+    (30, &[
+        &[(25, 18), (23, 15), (20, 14), (16, 14), (13, 15), (10, 16), (8, 19), (8, 21), (9, 24), (11, 26), (14, 27), (17, 28), (20, 29), (23, 30), (25, 32), (26, 35), (26, 37), (24, 40), (21, 41), (18, 42), (14, 42), (11, 41), (9, 38)],
+    ]),
+    // U+0074 (t)
+    (26, &[
+        &[(14, 2), (14, 36), (14, 38), (15, 40), (17, 41), (19, 42), (21, 42), (23, 41)],
+        &[(8, 14), (22, 14)],
+    ]),
+    // U+0075 (u)
+    (34, &[
+        &[(8, 14), (8, 34), (9, 37), (11, 40), (14, 41), (18, 42), (22, 41), (25, 40), (27, 37), (28, 34)],
+        &[(28, 14), (28, 42)],
+    ]),
+    // U+0076 (v)
+    (36, &[
+        &[(6, 14), (18, 42), (30, 14)],
+    ]),
+    // U+0077 (w)
+    (44, &[
+        &[(6, 14), (14, 42), (22, 14), (30, 42), (38, 14)],
+    ]),
+    // U+0078 (x)
+    (34, &[
+        &[(8, 14), (28, 42)],
+        &[(28, 14), (8, 42)],
+    ]),
+    // U+0079 (y)
+    (36, &[
+        &[(6, 14), (18, 42)],
+        &[(30, 14), (18, 42), (14, 51), (10, 55), (6, 56)],
+    ]),
+    // U+007A (z)
+    (34, &[
+        &[(8, 14), (28, 14), (8, 42), (28, 42)],
+    ]),
+    // U+007B ({)
+    (24, &[
+        &[(18, -6), (14, -4), (12, 0), (12, 16), (8, 21), (12, 26), (12, 42), (14, 46), (18, 48)],
+    ]),
+    // U+007C (|)
+    (16, &[
+        &[(8, -6), (8, 48)],
+    ]),
+    // U+007D (})
+    (24, &[
+        &[(6, -6), (10, -4), (12, 0), (12, 16), (16, 21), (12, 26), (12, 42), (10, 46), (6, 48)],
+    ]),
+    // U+007E (~)
+    (40, &[
+        &[(6, 26), (10, 22), (16, 21), (24, 26), (30, 25), (34, 22)],
+    ]),
+];
@@ -0,0 +1,147 @@
+use super::simplex::{SIMPLEX, StrokeGlyph};
+
+/// Font units from the top of the capitals to the baseline
+const CAP_HEIGHT: f32 = 42.0;
+/// Font units from the top of the capitals to the bottom of the descenders
+const EXTENT: f32 = 56.0;
+/// Font units between the tops of two lines
+const LINE_ADVANCE: f32 = 64.0;
+
+/// How [Graphics::stroke_text](crate::Graphics::stroke_text) draws the built in vector font. <br>
+/// **Defaults to 16px capitals, no rotation and 1px strokes**
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct StrokeStyle {
+    /// Height of the capital letters in pixels, anything works since the glyphs are lines
+    pub size: f32,
+    /// Rotation around the position the text is drawn at in radians, positive angles turn clockwise
+    pub angle: f32,
+    /// Width of the strokes in pixels
+    pub thickness: u32,
+}
+
+impl Default for StrokeStyle {
+    fn default() -> Self {
+        Self { size: 16.0, angle: 0.0, thickness: 1 }
+    }
+}
+
+impl StrokeStyle {
+    /// Capitals `size` pixels tall
+    pub fn sized(size: f32) -> Self {
+        Self { size, ..Self::default() }
+    }
+    /// Rotate the text by `angle` radians around where it's drawn
+    pub fn with_angle(mut self, angle: f32) -> Self {
+        self.angle = angle;
+        self
+    }
+    /// Draw the strokes `thickness` pixels wide, 0 is treated as 1
+    pub fn with_thickness(mut self, thickness: u32) -> Self {
+        self.thickness = thickness.max(1);
+        self
+    }
+
+    fn scale(&self) -> f32 {
+        self.size / CAP_HEIGHT
+    }
+
+    /// Width of a line in pixels before rotation
+    pub(crate) fn line_width(&self, line: &str) -> f32 {
+        let mut x = 0.0;
+        for c in line.chars() {
+            x = advance(x, c);
+        }
+        x * self.scale()
+    }
+    /// Height of `lines` lines in pixels before rotation, from the top of the capitals to the last descender
+    pub(crate) fn block_height(&self, lines: usize) -> f32 {
+        match lines {
+            0 => 0.0,
+            n => (LINE_ADVANCE * (n - 1) as f32 + EXTENT) * self.scale(),
+        }
+    }
+
+    /// Lay out `text` as polylines in pixels relative to where it's drawn, already rotated. <br>
+    /// `\n` starts a new line and `\t` jumps to the next multiple of 4 spaces, characters the font doesn't have are drawn as `?`.
+    pub(crate) fn layout(&self, text: &str) -> Vec<Vec<(f32, f32)>> {
+        let scale = self.scale();
+        let (sin, cos) = self.angle.sin_cos();
+        let mut polylines = Vec::new();
+
+        for (row, line) in super::lines(text).enumerate() {
+            let top = row as f32 * LINE_ADVANCE;
+            let mut pen = 0.0;
+            for c in line.chars() {
+                let strokes = if c == '\t' { &[][..] } else { glyph(c).1 };
+                for stroke in strokes {
+                    polylines.push(
+                        stroke
+                            .iter()
+                            .map(|&(x, y)| {
+                                let (x, y) = ((pen + x as f32) * scale, (top + y as f32) * scale);
+                                (x * cos - y * sin, x * sin + y * cos)
+                            })
+                            .collect(),
+                    );
+                }
+                pen = advance(pen, c);
+            }
+        }
+        polylines
+    }
+}
+
+fn glyph(c: char) -> &'static StrokeGlyph {
+    let fallback = &SIMPLEX['?' as usize - 0x20];
+    (c as usize).checked_sub(0x20).and_then(|i| SIMPLEX.get(i)).unwrap_or(fallback)
+}
+
+/// Where the pen ends up after `c` in font units
+fn advance(pen: f32, c: char) -> f32 {
+    if c == '\t' {
+        let stop = SIMPLEX[0].0 as f32 * 4.0;
+        return ((pen / stop).floor() + 1.0) * stop;
+    }
+    pen + glyph(c).0 as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(a: (f32, f32), b: (f32, f32)) -> bool {
+        (a.0 - b.0).abs() < 1e-3 && (a.1 - b.1).abs() < 1e-3
+    }
+
+    #[test]
+    fn measurements_scale_with_the_size() {
+        // At 42px a font unit is a pixel
+        let unit = StrokeStyle::sized(CAP_HEIGHT);
+        assert_eq!(unit.line_width("L"), 32.0);
+        assert_eq!(unit.line_width("L-L"), 32.0 + 36.0 + 32.0);
+        assert_eq!((unit.block_height(0), unit.block_height(1), unit.block_height(3)), (0.0, 56.0, 184.0));
+
+        let half = StrokeStyle::sized(21.0);
+        assert_eq!(half.line_width("L-L"), 50.0);
+        assert_eq!(half.block_height(3), 92.0);
+
+        // Tabs jump to the next multiple of 4 spaces, unknown characters are as wide as `?`
+        assert_eq!(unit.line_width("\t"), 128.0);
+        assert_eq!(unit.line_width("LLLL\tL"), 256.0 + 32.0);
+        assert_eq!(unit.line_width("ä"), unit.line_width("?"));
+    }
+
+    #[test]
+    fn layout_places_and_rotates_the_strokes() {
+        let style = StrokeStyle::sized(CAP_HEIGHT);
+        assert_eq!(style.layout("-"), [vec![(8.0, 24.0), (28.0, 24.0)]]);
+        // The second line starts one line advance down, whitespace has no strokes
+        assert_eq!(style.layout(" \t\n-"), [vec![(8.0, 88.0), (28.0, 88.0)]]);
+
+        let turned = style.with_angle(std::f32::consts::FRAC_PI_2).layout("-");
+        assert_eq!(turned.len(), 1);
+        assert!(close(turned[0][0], (-24.0, 8.0)) && close(turned[0][1], (-24.0, 28.0)), "{:?}", turned);
+
+        assert_eq!(StrokeStyle::default().with_thickness(0).thickness, 1);
+    }
+}
@@ -0,0 +1,66 @@
+use crate::color::Color;
+use crate::vector::Vec2;
+
+/// How [Graphics::text](crate::Graphics::text) draws bitmap text, set with [Graphics::set_text_style](crate::Graphics::set_text_style). <br>
+/// **Defaults to the font's own size, no extra line spacing, tab stops every 4 spaces and no effects**
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct TextStyle {
+    /// Every glyph pixel becomes `scale_x` pixels wide
+    pub scale_x: u32,
+    /// Every glyph pixel becomes `scale_y` pixels tall
+    pub scale_y: u32,
+    /// Extra pixels between two lines, negative values pull lines closer together
+    pub line_spacing: i32,
+    /// Tabs jump to the next multiple of `tab_width` spaces from the start of the line
+    pub tab_width: u32,
+    /// Fill a rectangle of this color behind every line
+    pub background: Option<Color>,
+    /// Draw the text again offset by `.0` in the color `.1` behind it
+    pub shadow: Option<(Vec2, Color)>,
+    /// Draw a 1px outline of this color around every glyph
+    pub outline: Option<Color>,
+}
+
+impl Default for TextStyle {
+    fn default() -> Self {
+        Self { scale_x: 1, scale_y: 1, line_spacing: 0, tab_width: 4, background: None, shadow: None, outline: None }
+    }
+}
+
+impl TextStyle {
+    /// Scale glyphs by `scale` in both directions, eg. 2 turns the 8x8 font into 16x16
+    pub fn scaled(scale: u32) -> Self {
+        Self::default().with_scale(scale, scale)
+    }
+    /// Scale glyphs separately horizontally and vertically, a scale of 0 is treated as 1
+    pub fn with_scale(mut self, x: u32, y: u32) -> Self {
+        self.scale_x = x.max(1);
+        self.scale_y = y.max(1);
+        self
+    }
+    /// Put `spacing` extra pixels between lines, this isn't scaled
+    pub fn with_line_spacing(mut self, spacing: i32) -> Self {
+        self.line_spacing = spacing;
+        self
+    }
+    /// Put tab stops every `width` spaces, a width of 0 is treated as 1
+    pub fn with_tab_width(mut self, width: u32) -> Self {
+        self.tab_width = width.max(1);
+        self
+    }
+    /// Fill a rectangle of `color` behind every line, eg. to keep labels readable on busy backgrounds
+    pub fn with_background(mut self, color: Color) -> Self {
+        self.background = Some(color);
+        self
+    }
+    /// Drop a shadow of `color` behind the text, `offset` is in pixels, eg. `(1, 1)` for down right
+    pub fn with_shadow(mut self, offset: impl Into<Vec2>, color: Color) -> Self {
+        self.shadow = Some((offset.into(), color));
+        self
+    }
+    /// Draw a 1px outline of `color` around every glyph, this isn't scaled
+    pub fn with_outline(mut self, color: Color) -> Self {
+        self.outline = Some(color);
+        self
+    }
+}
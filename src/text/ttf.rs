@@ -0,0 +1,241 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::io;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+use fontdue::{FontSettings, Metrics};
+
+use super::TextMask;
+use crate::vector::Vec2;
+
+/// How the glyph edges of a [Font] are drawn
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+pub enum TextMode {
+    /// Edges are blended with what's behind them
+    #[default]
+    AntiAliased,
+    /// Every pixel is either fully on or off, fits pixel art better
+    Crisp,
+}
+
+/// A TrueType/OpenType font that can be drawn at any pixel size with [Graphics::text_with](crate::Graphics::text_with). <br>
+/// Cloning is cheap, clones share the font data and the glyph cache.
+#[derive(Clone)]
+pub struct Font {
+    inner: Arc<FontInner>,
+    mode: TextMode,
+}
+
+/// Glyphs kept rasterized before the cache starts over, enough for a few sizes of a whole alphabet
+const CACHE_LIMIT: usize = 1024;
+
+struct FontInner {
+    font: fontdue::Font,
+    /// Rasterized glyphs by glyph index and pixel size, cleared once it holds [CACHE_LIMIT] glyphs
+    cache: Mutex<HashMap<(u16, u32), Arc<Glyph>>>,
+}
+
+struct Glyph {
+    metrics: Metrics,
+    coverage: Vec<u8>,
+}
+
+impl Font {
+    /// Load a font from the bytes of a .ttf or .otf file
+    pub fn from_bytes(bytes: impl Into<Vec<u8>>) -> Result<Self, &'static str> {
+        let font = fontdue::Font::from_bytes(bytes.into(), FontSettings::default())?;
+        Ok(Self {
+            inner: Arc::new(FontInner {
+                font,
+                cache: Mutex::new(HashMap::new()),
+            }),
+            mode: TextMode::default(),
+        })
+    }
+    /// Load a font from a .ttf or .otf file
+    pub fn from_file(path: impl AsRef<Path>) -> io::Result<Self> {
+        let bytes = std::fs::read(path)?;
+        Self::from_bytes(bytes).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+    }
+    /// Use another [TextMode], the glyph cache is still shared with the original font
+    pub fn with_mode(mut self, mode: TextMode) -> Self {
+        self.mode = mode;
+        self
+    }
+    /// The [TextMode] this font is drawn with
+    pub fn mode(&self) -> TextMode {
+        self.mode
+    }
+    /// Distance from the top of a line to its baseline at the given pixel size
+    pub fn ascent(&self, size: f32) -> f32 {
+        self.inner
+            .font
+            .horizontal_line_metrics(size)
+            .map_or(size, |m| m.ascent)
+    }
+    /// Distance between the baselines of two lines at the given pixel size
+    pub fn line_height(&self, size: f32) -> f32 {
+        self.inner
+            .font
+            .horizontal_line_metrics(size)
+            .map_or(size, |m| m.new_line_size)
+    }
+
+    /// Width of a line of text in pixels at the given pixel size, including kerning
+    pub fn text_width(&self, size: f32, text: &str) -> f32 {
+        let mut width = 0.0;
+        let mut previous = None;
+        for c in text.chars() {
+            let index = self.inner.font.lookup_glyph_index(c);
+            if let Some(prev) = previous {
+                width += self.inner.font.horizontal_kern_indexed(prev, index, size).unwrap_or(0.0);
+            }
+            width += self.inner.font.metrics_indexed(index, size).advance_width;
+            previous = Some(index);
+        }
+        width
+    }
+
+    fn glyph(&self, index: u16, size: f32) -> Arc<Glyph> {
+        let mut cache = self.inner.cache.lock().unwrap_or_else(|err| err.into_inner());
+        // Animated sizes would add new glyphs every frame, so don't let them pile up
+        if cache.len() >= CACHE_LIMIT && !cache.contains_key(&(index, size.to_bits())) {
+            cache.clear();
+        }
+        cache
+            .entry((index, size.to_bits()))
+            .or_insert_with(|| {
+                let (metrics, coverage) = self.inner.font.rasterize_indexed(index, size);
+                Arc::new(Glyph { metrics, coverage })
+            })
+            .clone()
+    }
+
+    /// Lay out and rasterize a line of text. <br>
+    /// The top of the line sits at y 0 and the text starts at x 0, glyphs may still stick out a bit.
+    pub(crate) fn rasterize(&self, size: f32, text: &str) -> TextMask {
+        let baseline = self.ascent(size).round() as i32;
+        let mut placed = Vec::new();
+        let mut pen_x = 0.0;
+        let mut previous = None;
+
+        for c in text.chars() {
+            let index = self.inner.font.lookup_glyph_index(c);
+            if let Some(prev) = previous {
+                pen_x += self.inner.font.horizontal_kern_indexed(prev, index, size).unwrap_or(0.0);
+            }
+            let glyph = self.glyph(index, size);
+            let x = pen_x.round() as i32 + glyph.metrics.xmin;
+            let y = baseline - glyph.metrics.ymin - glyph.metrics.height as i32;
+            pen_x += glyph.metrics.advance_width;
+            previous = Some(index);
+
+            if glyph.metrics.width > 0 && glyph.metrics.height > 0 {
+                placed.push((x, y, glyph));
+            }
+        }
+
+        if placed.is_empty() {
+            return TextMask::empty();
+        }
+
+        let left = placed.iter().map(|(x, _, _)| *x).min().unwrap_or(0);
+        let top = placed.iter().map(|(_, y, _)| *y).min().unwrap_or(0);
+        let right = placed.iter().map(|(x, _, g)| x + g.metrics.width as i32).max().unwrap_or(0);
+        let bottom = placed.iter().map(|(_, y, g)| y + g.metrics.height as i32).max().unwrap_or(0);
+        let (width, height) = ((right - left) as usize, (bottom - top) as usize);
+
+        let mut alpha = vec![0u8; width * height];
+        for (x, y, glyph) in placed {
+            let (gx, gy) = ((x - left) as usize, (y - top) as usize);
+            for (row, line) in glyph.coverage.chunks_exact(glyph.metrics.width).enumerate() {
+                let start = (gy + row) * width + gx;
+                // Glyphs can overlap a little, keep the strongest coverage
+                for (dst, src) in alpha[start..start + line.len()].iter_mut().zip(line) {
+                    *dst = (*dst).max(*src);
+                }
+            }
+        }
+
+        if self.mode == TextMode::Crisp {
+            for a in &mut alpha {
+                *a = if *a >= 128 { 255 } else { 0 };
+            }
+        }
+
+        TextMask {
+            offset: Vec2::new(left, top),
+            size: Vec2::new(width as i32, height as i32),
+            alpha,
+        }
+    }
+}
+
+impl fmt::Debug for Font {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Font")
+            .field("name", &self.inner.font.name())
+            .field("mode", &self.mode)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 1000 units per em, ascent 800 and descent 200. `I` is a 300x800 bar at x 100 with an advance of 500,
+    /// `_` fills the whole advance from y -200 to -100
+    fn rects() -> Font {
+        Font::from_bytes(include_bytes!("../../tests/fonts/rects.ttf").as_slice()).unwrap()
+    }
+
+    /// Mask as rows of `#` (fully covered) and `.`
+    fn picture(mask: &TextMask) -> Vec<String> {
+        mask.alpha
+            .chunks(mask.size.x as usize)
+            .map(|row| row.iter().map(|a| if *a == 255 { '#' } else { '.' }).collect())
+            .collect()
+    }
+
+    #[test]
+    fn metrics_scale_with_the_size() {
+        let font = rects();
+        assert_eq!((font.ascent(10.0), font.line_height(10.0)), (8.0, 10.0));
+        assert_eq!((font.ascent(25.0), font.line_height(25.0)), (20.0, 25.0));
+        assert_eq!(font.text_width(10.0, "I I_"), 20.0);
+        assert_eq!(font.text_width(10.0, ""), 0.0);
+    }
+
+    #[test]
+    fn glyphs_land_on_the_baseline() {
+        let font = rects().with_mode(TextMode::Crisp);
+        let mask = font.rasterize(10.0, "I_");
+        assert_eq!(mask.offset, Vec2::new(1, 0));
+        assert_eq!(mask.size, Vec2::new(9, 10));
+        let bar = "###......";
+        let mut expected = vec![bar; 8];
+        // The underscore hangs a pixel below the baseline
+        expected.push(".........");
+        expected.push("....#####");
+        assert_eq!(picture(&mask), expected);
+
+        assert!(font.rasterize(10.0, "  ").alpha.is_empty());
+    }
+
+    #[test]
+    fn the_cache_stays_bounded() {
+        let font = rects();
+        for step in 0..CACHE_LIMIT * 2 {
+            font.rasterize(8.0 + step as f32 / 16.0, "I_");
+        }
+        let cached = font.inner.cache.lock().unwrap().len();
+        assert!(cached <= CACHE_LIMIT, "{} glyphs cached", cached);
+
+        // Clones share the cache
+        let clone = font.clone().with_mode(TextMode::Crisp);
+        clone.rasterize(99.0, "I");
+        assert!(font.inner.cache.lock().unwrap().contains_key(&(font.inner.font.lookup_glyph_index('I'), 99.0f32.to_bits())));
+    }
+}
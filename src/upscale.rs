@@ -0,0 +1,288 @@
+/// Pixel art upscaling filter applied to the canvas before it's presented. <br>
+/// Small logical resolutions (eg. 64x32 for CHIP-8) look a lot smoother with these than with plain nearest neighbor scaling. <br>
+/// **Defaults to [Upscaler::None]**
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+pub enum Upscaler {
+    /// Present the canvas as is
+    #[default]
+    None,
+    /// Scale2x/EPX, doubles the canvas size
+    Scale2x,
+    /// Scale3x, triples the canvas size
+    Scale3x,
+    /// Scale2x applied twice, quadruples the canvas size
+    Scale4x,
+    /// A 2xBR style filter. Doubles the canvas size and blends the edges instead of just moving pixels around
+    Xbr2x,
+}
+
+type Pixel = [u8; 4];
+/// Turns a neighbor offset around the center pixel
+type Rotation = fn(i32, i32) -> (i32, i32);
+
+impl Upscaler {
+    /// How many times bigger the output is compared to the canvas
+    pub fn factor(&self) -> u32 {
+        match self {
+            Upscaler::None => 1,
+            Upscaler::Scale2x | Upscaler::Xbr2x => 2,
+            Upscaler::Scale3x => 3,
+            Upscaler::Scale4x => 4,
+        }
+    }
+
+    /// Upscale a RGBA `src` frame of the given size into `dst`. <br>
+    /// `dst` must be [Self::factor] times wider and taller than `src`.
+    pub fn upscale(&self, src: &[u8], width: u32, height: u32, dst: &mut [u8]) {
+        let factor = self.factor();
+        assert_eq!(
+            dst.len(),
+            src.len() * (factor * factor) as usize,
+            "Upscaler destination has the wrong size"
+        );
+
+        match self {
+            Upscaler::None => dst.copy_from_slice(src),
+            Upscaler::Scale2x => scale2x(src, width, height, dst),
+            Upscaler::Scale3x => scale3x(src, width, height, dst),
+            Upscaler::Scale4x => {
+                let mut half = vec![0; src.len() * 4];
+                scale2x(src, width, height, &mut half);
+                scale2x(&half, width * 2, height * 2, dst);
+            }
+            Upscaler::Xbr2x => xbr2x(src, width, height, dst),
+        }
+    }
+}
+
+/// Read a pixel, positions outside of the frame are clamped to the edge
+fn get(src: &[u8], width: u32, height: u32, x: i32, y: i32) -> Pixel {
+    let x = x.clamp(0, width as i32 - 1) as usize;
+    let y = y.clamp(0, height as i32 - 1) as usize;
+    let idx = (y * width as usize + x) * 4;
+    [src[idx], src[idx + 1], src[idx + 2], src[idx + 3]]
+}
+
+fn put(dst: &mut [u8], width: u32, x: u32, y: u32, px: Pixel) {
+    let idx = ((y * width + x) * 4) as usize;
+    dst[idx..idx + 4].copy_from_slice(&px);
+}
+
+fn scale2x(src: &[u8], width: u32, height: u32, dst: &mut [u8]) {
+    let out_width = width * 2;
+
+    for y in 0..height {
+        for x in 0..width {
+            let (xi, yi) = (x as i32, y as i32);
+            let b = get(src, width, height, xi, yi - 1);
+            let d = get(src, width, height, xi - 1, yi);
+            let e = get(src, width, height, xi, yi);
+            let f = get(src, width, height, xi + 1, yi);
+            let h = get(src, width, height, xi, yi + 1);
+
+            let (mut e0, mut e1, mut e2, mut e3) = (e, e, e, e);
+            if b != h && d != f {
+                if d == b { e0 = d; }
+                if b == f { e1 = f; }
+                if d == h { e2 = d; }
+                if h == f { e3 = f; }
+            }
+
+            put(dst, out_width, x * 2, y * 2, e0);
+            put(dst, out_width, x * 2 + 1, y * 2, e1);
+            put(dst, out_width, x * 2, y * 2 + 1, e2);
+            put(dst, out_width, x * 2 + 1, y * 2 + 1, e3);
+        }
+    }
+}
+
+fn scale3x(src: &[u8], width: u32, height: u32, dst: &mut [u8]) {
+    let out_width = width * 3;
+
+    for y in 0..height {
+        for x in 0..width {
+            let (xi, yi) = (x as i32, y as i32);
+            let a = get(src, width, height, xi - 1, yi - 1);
+            let b = get(src, width, height, xi, yi - 1);
+            let c = get(src, width, height, xi + 1, yi - 1);
+            let d = get(src, width, height, xi - 1, yi);
+            let e = get(src, width, height, xi, yi);
+            let f = get(src, width, height, xi + 1, yi);
+            let g = get(src, width, height, xi - 1, yi + 1);
+            let h = get(src, width, height, xi, yi + 1);
+            let i = get(src, width, height, xi + 1, yi + 1);
+
+            let mut out = [e; 9];
+            if b != h && d != f {
+                if d == b { out[0] = d; }
+                if (d == b && e != c) || (b == f && e != a) { out[1] = b; }
+                if b == f { out[2] = f; }
+                if (d == b && e != g) || (d == h && e != a) { out[3] = d; }
+                if (b == f && e != i) || (h == f && e != c) { out[5] = f; }
+                if d == h { out[6] = d; }
+                if (d == h && e != i) || (h == f && e != g) { out[7] = h; }
+                if h == f { out[8] = f; }
+            }
+
+            for (n, px) in out.into_iter().enumerate() {
+                let n = n as u32;
+                put(dst, out_width, x * 3 + n % 3, y * 3 + n / 3, px);
+            }
+        }
+    }
+}
+
+/// Color difference weighted by how sensitive the eye is to each channel
+fn diff(a: Pixel, b: Pixel) -> u32 {
+    let dr = (a[0] as i32 - b[0] as i32).unsigned_abs();
+    let dg = (a[1] as i32 - b[1] as i32).unsigned_abs();
+    let db = (a[2] as i32 - b[2] as i32).unsigned_abs();
+    let da = (a[3] as i32 - b[3] as i32).unsigned_abs();
+    dr * 3 + dg * 6 + db + da * 2
+}
+
+fn blend(a: Pixel, b: Pixel) -> Pixel {
+    [
+        ((a[0] as u16 + b[0] as u16) / 2) as u8,
+        ((a[1] as u16 + b[1] as u16) / 2) as u8,
+        ((a[2] as u16 + b[2] as u16) / 2) as u8,
+        ((a[3] as u16 + b[3] as u16) / 2) as u8,
+    ]
+}
+
+fn xbr2x(src: &[u8], width: u32, height: u32, dst: &mut [u8]) {
+    let out_width = width * 2;
+    // Rotations that turn the bottom right corner into the other 3 corners
+    let corners: [(u32, u32, Rotation); 4] = [
+        (1, 1, |x, y| (x, y)),
+        (0, 1, |x, y| (-y, x)),
+        (0, 0, |x, y| (-x, -y)),
+        (1, 0, |x, y| (y, -x)),
+    ];
+
+    for y in 0..height {
+        for x in 0..width {
+            for (ox, oy, rotate) in corners {
+                let at = |dx: i32, dy: i32| {
+                    let (dx, dy) = rotate(dx, dy);
+                    get(src, width, height, x as i32 + dx, y as i32 + dy)
+                };
+                put(dst, out_width, x * 2 + ox, y * 2 + oy, xbr_corner(at));
+            }
+        }
+    }
+}
+
+/// Works out the bottom right corner of `E`, neighbors are read relative to it:
+/// ```text
+///       .  B  C
+///    D  E  F  F4
+///    G  H  I  I4
+///       H5 I5
+/// ```
+fn xbr_corner(at: impl Fn(i32, i32) -> Pixel) -> Pixel {
+    let e = at(0, 0);
+    let f = at(1, 0);
+    let h = at(0, 1);
+    if e == f || e == h {
+        return e;
+    }
+
+    let (b, c, d, g, i) = (at(0, -1), at(1, -1), at(-1, 0), at(-1, 1), at(1, 1));
+    let (f4, i4, h5, i5) = (at(2, 0), at(2, 1), at(0, 2), at(1, 2));
+
+    // How much the colors change along each diagonal, a smaller value means an edge runs that way
+    let along_hf = diff(e, c) + diff(e, g) + diff(i, f4) + diff(i, h5) + 4 * diff(h, f);
+    let along_ei = diff(h, d) + diff(h, i5) + diff(f, i4) + diff(f, b) + 4 * diff(e, i);
+
+    if along_hf < along_ei {
+        let closest = if diff(e, f) <= diff(e, h) { f } else { h };
+        blend(e, closest)
+    } else {
+        e
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WHITE: Pixel = [255, 255, 255, 255];
+    const BLACK: Pixel = [0, 0, 0, 255];
+    const ALL: [Upscaler; 5] = [Upscaler::None, Upscaler::Scale2x, Upscaler::Scale3x, Upscaler::Scale4x, Upscaler::Xbr2x];
+
+    /// `#` is black, `.` white
+    fn image(rows: &[&str]) -> (Vec<u8>, u32, u32) {
+        let pixels = rows.iter().flat_map(|row| row.chars().flat_map(|c| if c == '#' { BLACK } else { WHITE }));
+        (pixels.collect(), rows[0].len() as u32, rows.len() as u32)
+    }
+
+    /// Upscale and draw the result the same way, halfway blends show up as `+`
+    fn upscaled(upscaler: Upscaler, rows: &[&str]) -> Vec<String> {
+        let (src, width, height) = image(rows);
+        let factor = upscaler.factor();
+        let mut dst = vec![0; src.len() * (factor * factor) as usize];
+        upscaler.upscale(&src, width, height, &mut dst);
+
+        dst.chunks((width * factor * 4) as usize)
+            .map(|row| {
+                row.chunks(4)
+                    .map(|px| match px {
+                        [0, 0, 0, 255] => '#',
+                        [255, 255, 255, 255] => '.',
+                        [127, 127, 127, 255] => '+',
+                        _ => '?',
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn flat_areas_stay_flat() {
+        for upscaler in ALL {
+            let factor = upscaler.factor() as usize;
+            for (rows, expected) in [(["###", "###"], "#"), (["...", "..."], ".")] {
+                let out = upscaled(upscaler, &rows);
+                assert_eq!(out.len(), 2 * factor, "{:?}", upscaler);
+                assert!(out.iter().all(|row| *row == expected.repeat(3 * factor)), "{:?}: {:?}", upscaler, out);
+            }
+        }
+    }
+
+    #[test]
+    fn scale2x_diagonal() {
+        assert_eq!(upscaled(Upscaler::Scale2x, &["#.", "##"]), ["##..", "###.", "####", "####"]);
+        // A lone pixel has no edges to follow
+        assert_eq!(upscaled(Upscaler::Scale2x, &["...", ".#.", "..."])[2..4], ["..##..", "..##.."]);
+    }
+
+    #[test]
+    fn scale3x_diagonal() {
+        assert_eq!(
+            upscaled(Upscaler::Scale3x, &["#.", "##"]),
+            ["###...", "####..", "#####.", "######", "######", "######"]
+        );
+    }
+
+    #[test]
+    fn scale4x_is_scale2x_twice() {
+        assert_eq!(
+            upscaled(Upscaler::Scale4x, &["#.", "##"]),
+            ["####....", "#####...", "#####...", "#######.", "########", "########", "########", "########"]
+        );
+    }
+
+    #[test]
+    fn xbr2x_blends_the_diagonal() {
+        assert_eq!(upscaled(Upscaler::Xbr2x, &["#.", "##"]), ["##..", "##+.", "####", "####"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "wrong size")]
+    fn destination_has_to_fit() {
+        let (src, width, height) = image(&["#.", "##"]);
+        let mut dst = vec![0; src.len() * 4 - 1];
+        Upscaler::Scale2x.upscale(&src, width, height, &mut dst);
+    }
+}
@@ -2,7 +2,8 @@
 /// Vec2 Represents a 2 Dimentional point. <br>
 /// (i32,i32) and (f32,f32) Tuples could be converted to `Vec2` by using `into`.  <br>
 /// _Note : When using `into` with floating point numbers it gets casted into i32(using .floor) and loses precision_
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "record", derive(serde::Serialize, serde::Deserialize))]
 pub struct Vec2{
     pub x: i32,
     pub y: i32
@@ -18,24 +19,24 @@ impl Vec2 {
     }
 }
 
-impl Into<Vec2> for (i32,i32) {
-    fn into(self) -> Vec2 {
-        Vec2 { x: self.0, y: self.1 }
+impl From<(i32,i32)> for Vec2 {
+    fn from(value: (i32,i32)) -> Vec2 {
+        Vec2 { x: value.0, y: value.1 }
     }
 }
 
-impl Into<Vec2> for (u32,u32) {
-    fn into(self) -> Vec2 {
-        Vec2 { x: self.0 as i32, y: self.1 as i32 }
+impl From<(u32,u32)> for Vec2 {
+    fn from(value: (u32,u32)) -> Vec2 {
+        Vec2 { x: value.0 as i32, y: value.1 as i32 }
     }
 }
 
-impl Into<Vec2> for (f32,f32) {
-    fn into(self) -> Vec2 {
+impl From<(f32,f32)> for Vec2 {
+    fn from(value: (f32,f32)) -> Vec2 {
         Vec2 { 
-            x: self.0.floor() as i32,
+            x: value.0.floor() as i32,
 
-            y: self.1.floor() as i32, 
+            y: value.1.floor() as i32, 
         }
     }
 }
@@ -53,6 +54,7 @@ impl std::ops::Sub for Vec2 {
     }
 }
 /// A Typed Rectangle. Can be used for bounds checking
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct Rect{
     pub pos: Vec2,
     pub size: Vec2,
@@ -61,8 +63,8 @@ pub struct Rect{
 impl Rect {
     pub fn new(pos: Vec2, size: Vec2) -> Self {
         Self {
-            pos: pos,
-            size: size,
+            pos,
+            size,
         }
     }
     